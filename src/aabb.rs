@@ -0,0 +1,203 @@
+use crate::ray::Ray;
+use crate::vec3::Vec3;
+use num_traits::Float;
+
+/// An axis-aligned bounding box spanning `min..=max` on every axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T: Float> Aabb<T> {
+    pub fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Aabb { min, max }
+    }
+
+    /// Smallest box containing every point. An empty slice gives an inverted
+    /// box (`min` at +infinity, `max` at -infinity) that contains nothing and
+    /// leaves any box it is unioned with unchanged.
+    pub fn from_points(points: &[Vec3<T>]) -> Aabb<T> {
+        let empty = Aabb {
+            min: Vec3::splat(T::infinity()),
+            max: Vec3::splat(T::neg_infinity()),
+        };
+        points.iter().fold(empty, |aabb, p| Aabb {
+            min: aabb.min.min(*p),
+            max: aabb.max.max(*p),
+        })
+    }
+
+    /// True if `p` is inside or on the boundary.
+    pub fn contains(&self, p: &Vec3<T>) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+
+    pub fn union(&self, other: &Aabb<T>) -> Aabb<T> {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// True if the boxes overlap; boxes that only touch on a face, edge, or
+    /// corner count as intersecting.
+    pub fn intersects(&self, other: &Aabb<T>) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Slab test against `ray`, returning the entry and exit parameters. The
+    /// entry is negative when the ray starts inside the box. Returns `None` if
+    /// the ray misses or the box lies entirely behind the origin. Touching a
+    /// face, edge, or corner counts as a hit.
+    pub fn intersect_ray(&self, ray: &Ray<T>) -> Option<(T, T)> {
+        let mut t_enter = T::neg_infinity();
+        let mut t_exit = T::infinity();
+        for axis in 0..3 {
+            let origin = ray.origin[axis];
+            let dir = ray.dir[axis];
+            let (lo, hi) = (self.min[axis], self.max[axis]);
+            if dir == T::zero() {
+                // Parallel to this slab: either always inside it, leaving the
+                // (-inf, inf) interval untouched, or never. Dividing instead
+                // would give 0 * inf = NaN for an origin on the boundary.
+                if origin < lo || origin > hi {
+                    return None;
+                }
+                continue;
+            }
+            let inv = T::one() / dir;
+            let t0 = (lo - origin) * inv;
+            let t1 = (hi - origin) * inv;
+            t_enter = t_enter.max(t0.min(t1));
+            t_exit = t_exit.min(t0.max(t1));
+        }
+        if t_enter > t_exit || t_exit < T::zero() {
+            return None;
+        }
+        Some((t_enter, t_exit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box() -> Aabb<f32> {
+        Aabb::new(Vec3::new(), Vec3::splat(1))
+    }
+
+    #[test]
+    fn contains_interior_and_boundary() {
+        let aabb = unit_box();
+        assert!(aabb.contains(&Vec3::from((0.5, 0.5, 0.5))));
+        assert!(aabb.contains(&Vec3::from((0.0, 0.0, 0.0))));
+        assert!(aabb.contains(&Vec3::from((1.0, 0.5, 1.0))));
+        assert!(!aabb.contains(&Vec3::from((1.0, 1.0, 1.0001))));
+        assert!(!aabb.contains(&Vec3::from((-0.1, 0.5, 0.5))));
+    }
+
+    #[test]
+    fn union_grows_box() {
+        let a = unit_box();
+        let b = Aabb::new(Vec3::from((-1.0, 0.5, 0.5)), Vec3::from((0.5, 3.0, 0.5)));
+        let u = a.union(&b);
+        assert_eq!(u.min, Vec3::from((-1.0, 0.0, 0.0)));
+        assert_eq!(u.max, Vec3::from((1.0, 3.0, 1.0)));
+        assert!(u.contains(&Vec3::from((-1.0, 3.0, 0.0))));
+    }
+
+    #[test]
+    fn from_points_bounds_all_points() {
+        let points: [Vec3<f32>; 3] = [
+            (1.0, -2.0, 3.0).into(),
+            (-1.0, 4.0, 0.0).into(),
+            (0.0, 0.0, 5.0).into(),
+        ];
+        let aabb = Aabb::from_points(&points);
+        assert_eq!(aabb.min, Vec3::from((-1.0, -2.0, 0.0)));
+        assert_eq!(aabb.max, Vec3::from((1.0, 4.0, 5.0)));
+        assert!(points.iter().all(|p| aabb.contains(p)));
+    }
+
+    #[test]
+    fn from_no_points_is_empty() {
+        let empty: Aabb<f32> = Aabb::from_points(&[]);
+        assert!(!empty.contains(&Vec3::new()));
+        assert_eq!(empty.union(&unit_box()), unit_box());
+    }
+
+    #[test]
+    fn intersects_overlapping_and_touching() {
+        let a = unit_box();
+        let overlapping = Aabb::new(Vec3::splat(0.5), Vec3::splat(2));
+        let touching = Aabb::new(Vec3::from((1.0, 0.0, 0.0)), Vec3::from((2.0, 1.0, 1.0)));
+        let apart = Aabb::new(Vec3::from((1.5, 0.0, 0.0)), Vec3::from((2.0, 1.0, 1.0)));
+        assert!(a.intersects(&overlapping));
+        assert!(a.intersects(&touching));
+        assert!(!a.intersects(&apart));
+        assert!(!apart.intersects(&a));
+    }
+
+    // ============ Ray Intersection Tests ============
+
+    #[test]
+    fn ray_through_center() {
+        let ray = Ray::new(Vec3::from((-1.0, 0.5, 0.5)), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), Some((1.0, 2.0)));
+    }
+
+    #[test]
+    fn ray_diagonal_hit() {
+        let ray = Ray::new(Vec3::splat(-1), Vec3::splat(1));
+        assert_eq!(unit_box().intersect_ray(&ray), Some((1.0, 2.0)));
+    }
+
+    #[test]
+    fn ray_grazing_face() {
+        // Runs along the y = 1 face, parallel to it.
+        let ray = Ray::new(Vec3::from((-1.0, 1.0, 0.5)), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), Some((1.0, 2.0)));
+    }
+
+    #[test]
+    fn ray_along_edge() {
+        // Runs along the edge where the y = 0 and z = 0 faces meet.
+        let ray = Ray::new(Vec3::from((-1.0, 0.0, 0.0)), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), Some((1.0, 2.0)));
+    }
+
+    #[test]
+    fn ray_parallel_just_outside() {
+        let ray = Ray::new(Vec3::from((-1.0, 1.0001, 0.5)), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), None);
+    }
+
+    #[test]
+    fn ray_miss() {
+        let ray = Ray::new(Vec3::from((-1.0, 0.5, 0.5)), Vec3::from((1.0, 2.0, 0.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), None);
+    }
+
+    #[test]
+    fn ray_from_inside() {
+        let ray = Ray::new(Vec3::splat(0.5), Vec3::from((0.0, 0.0, -1.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), Some((-0.5, 0.5)));
+    }
+
+    #[test]
+    fn box_behind_ray() {
+        let ray = Ray::new(Vec3::from((2.0, 0.5, 0.5)), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(unit_box().intersect_ray(&ray), None);
+    }
+}
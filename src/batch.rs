@@ -0,0 +1,104 @@
+//! Whole-array operations over slices of `Vec3`, written as flat loops the
+//! compiler can autovectorize more readily than one method call per element.
+
+use crate::vec3::Vec3;
+use std::ops::{Add, Mul, Sub};
+
+/// Writes `a[i] + b[i]` into `out[i]`.
+///
+/// # Panics
+///
+/// Panics if the three slices differ in length.
+pub fn add_slices<T: Copy + Add<Output = T>>(a: &[Vec3<T>], b: &[Vec3<T>], out: &mut [Vec3<T>]) {
+    check_lengths("add_slices", a.len(), b.len(), out.len());
+    for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+        *o = x + y;
+    }
+}
+
+/// Writes `a[i] - b[i]` into `out[i]`.
+///
+/// # Panics
+///
+/// Panics if the three slices differ in length.
+pub fn sub_slices<T: Copy + Sub<Output = T>>(a: &[Vec3<T>], b: &[Vec3<T>], out: &mut [Vec3<T>]) {
+    check_lengths("sub_slices", a.len(), b.len(), out.len());
+    for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+        *o = x - y;
+    }
+}
+
+/// Multiplies every vector in `v` by `s` in place.
+pub fn scale_slice<T: Copy + Mul<Output = T>>(v: &mut [Vec3<T>], s: T) {
+    for x in v {
+        *x = *x * s;
+    }
+}
+
+fn check_lengths(name: &str, a: usize, b: usize, out: usize) {
+    assert!(
+        a == b && b == out,
+        "{name}: slice lengths must match (a: {a}, b: {b}, out: {out})"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(n: usize, scale: f32) -> Vec<Vec3<f32>> {
+        (0..n)
+            .map(|i| Vec3::from((i as f32 * scale, -(i as f32), 0.5 * scale)))
+            .collect()
+    }
+
+    #[test]
+    fn add_slices_matches_per_element() {
+        let a = ramp(1000, 1.5);
+        let b = ramp(1000, -0.25);
+        let mut out = vec![Vec3::<f32>::ZERO; 1000];
+        add_slices(&a, &b, &mut out);
+        for i in 0..1000 {
+            assert_eq!(out[i], a[i] + b[i]);
+        }
+    }
+
+    #[test]
+    fn sub_slices_matches_per_element() {
+        let a = ramp(100, 2.0);
+        let b = ramp(100, 3.0);
+        let mut out = vec![Vec3::<f32>::ZERO; 100];
+        sub_slices(&a, &b, &mut out);
+        for i in 0..100 {
+            assert_eq!(out[i], a[i] - b[i]);
+        }
+    }
+
+    #[test]
+    fn scale_slice_in_place() {
+        let original = ramp(10, 1.0);
+        let mut v = original.clone();
+        scale_slice(&mut v, 2.0);
+        for (scaled, orig) in v.iter().zip(&original) {
+            assert_eq!(*scaled, *orig * 2.0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "add_slices: slice lengths must match (a: 3, b: 2, out: 3)")]
+    fn add_slices_length_mismatch_panics() {
+        let a = ramp(3, 1.0);
+        let b = ramp(2, 1.0);
+        let mut out = vec![Vec3::<f32>::ZERO; 3];
+        add_slices(&a, &b, &mut out);
+    }
+
+    #[test]
+    #[should_panic(expected = "sub_slices: slice lengths must match")]
+    fn sub_slices_output_mismatch_panics() {
+        let a = ramp(3, 1.0);
+        let b = ramp(3, 1.0);
+        let mut out = vec![Vec3::<f32>::ZERO; 4];
+        sub_slices(&a, &b, &mut out);
+    }
+}
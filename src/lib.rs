@@ -0,0 +1,13 @@
+#[macro_use]
+mod macros;
+
+pub mod aabb;
+pub mod batch;
+pub mod mat3;
+pub mod mat4;
+pub mod quat;
+pub mod ray;
+pub mod transform;
+pub mod vec2;
+pub mod vec3;
+pub mod vec4;
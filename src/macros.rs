@@ -0,0 +1,238 @@
+// Methods, operators, and conversions shared by the fixed-size vector types.
+// Each macro takes the type name and its field list so `Vec2`, `Vec3`, and
+// `Vec4` are generated from one definition and cannot drift apart, while
+// keeping plain `x`/`y`/`z`/`w` fields rather than a const-generic array.
+
+macro_rules! impl_vec_common {
+    ($vec:ident { $first:ident $(, $rest:ident)* }) => {
+        impl<T: Default> $vec<T> {
+            pub fn new() -> Self {
+                $vec {
+                    $first: T::default(),
+                    $($rest: T::default(),)*
+                }
+            }
+        }
+
+        impl<T: num_traits::Float> $vec<T> {
+            pub fn splat<U: num_traits::ToPrimitive + Copy>(val: U) -> Self {
+                $vec {
+                    $first: num_traits::NumCast::from(val).unwrap(),
+                    $($rest: num_traits::NumCast::from(val).unwrap(),)*
+                }
+            }
+
+            pub fn length(&self) -> T {
+                self.length_squared().sqrt()
+            }
+
+            /// Returns the unit vector pointing in the same direction. A zero
+            /// vector is returned unchanged rather than turning into NaNs.
+            pub fn normalize(&self) -> $vec<T> {
+                self.try_normalize().unwrap_or(*self)
+            }
+
+            /// Like `normalize`, but returns `None` for a zero-length vector.
+            pub fn try_normalize(&self) -> Option<$vec<T>> {
+                let len_sq = self.length_squared();
+                if len_sq > T::zero() {
+                    let len = len_sq.sqrt();
+                    Some($vec {
+                        $first: self.$first / len,
+                        $($rest: self.$rest / len,)*
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>> $vec<T> {
+            pub fn dot(&self, other: &$vec<T>) -> T {
+                self.$first * other.$first $(+ self.$rest * other.$rest)*
+            }
+
+            /// Squared length, avoiding the square root when only comparing
+            /// magnitudes.
+            pub fn length_squared(&self) -> T {
+                self.dot(self)
+            }
+        }
+
+        impl<T> $vec<T> {
+            /// Applies `f` to each component, possibly changing the element
+            /// type.
+            pub fn map<U, F: Fn(T) -> U>(self, f: F) -> $vec<U> {
+                $vec {
+                    $first: f(self.$first),
+                    $($rest: f(self.$rest),)*
+                }
+            }
+
+            /// Combines matching components of `self` and `other` with `f`.
+            pub fn zip_with<U, F: Fn(T, T) -> U>(self, other: $vec<T>, f: F) -> $vec<U> {
+                $vec {
+                    $first: f(self.$first, other.$first),
+                    $($rest: f(self.$rest, other.$rest),)*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_component_op {
+    ($vec:ident { $($field:ident),+ }, $trait:ident, $method:ident, $op:tt) => {
+        impl<T: std::ops::$trait<Output = T>> std::ops::$trait for $vec<T> {
+            type Output = $vec<T>;
+            fn $method(self, other: $vec<T>) -> $vec<T> {
+                $vec {
+                    $($field: self.$field $op other.$field),+
+                }
+            }
+        }
+
+        impl<T: Copy + std::ops::$trait<Output = T>> std::ops::$trait<&$vec<T>> for &$vec<T> {
+            type Output = $vec<T>;
+            fn $method(self, other: &$vec<T>) -> $vec<T> {
+                $vec {
+                    $($field: self.$field $op other.$field),+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_op {
+    ($vec:ident { $($field:ident),+ }, $trait:ident, $method:ident, $op:tt) => {
+        impl<T: Copy + std::ops::$trait<Output = T>> std::ops::$trait<T> for $vec<T> {
+            type Output = $vec<T>;
+            fn $method(self, other: T) -> $vec<T> {
+                $vec {
+                    $($field: self.$field $op other),+
+                }
+            }
+        }
+
+        impl<T: Copy + std::ops::$trait<Output = T>> std::ops::$trait<T> for &$vec<T> {
+            type Output = $vec<T>;
+            fn $method(self, other: T) -> $vec<T> {
+                $vec {
+                    $($field: self.$field $op other),+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_component_assign_op {
+    ($vec:ident { $($field:ident),+ }, $trait:ident, $method:ident, $op:tt) => {
+        impl<T: std::ops::$trait> std::ops::$trait for $vec<T> {
+            fn $method(&mut self, other: $vec<T>) {
+                $(self.$field $op other.$field;)+
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_assign_op {
+    ($vec:ident { $($field:ident),+ }, $trait:ident, $method:ident, $op:tt) => {
+        impl<T: Copy + std::ops::$trait> std::ops::$trait<T> for $vec<T> {
+            fn $method(&mut self, other: T) {
+                $(self.$field $op other;)+
+            }
+        }
+    };
+}
+
+macro_rules! impl_neg {
+    ($vec:ident { $($field:ident),+ }) => {
+        impl<T: std::ops::Neg<Output = T>> std::ops::Neg for $vec<T> {
+            type Output = $vec<T>;
+            fn neg(self) -> $vec<T> {
+                $vec {
+                    $($field: -self.$field),+
+                }
+            }
+        }
+
+        impl<T: Copy + std::ops::Neg<Output = T>> std::ops::Neg for &$vec<T> {
+            type Output = $vec<T>;
+            fn neg(self) -> $vec<T> {
+                $vec {
+                    $($field: -self.$field),+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_sum {
+    ($vec:ident { $($field:ident),+ }) => {
+        impl<T: num_traits::Zero> std::iter::Sum for $vec<T> {
+            fn sum<I: Iterator<Item = $vec<T>>>(iter: I) -> $vec<T> {
+                let zero = $vec {
+                    $($field: T::zero()),+
+                };
+                iter.fold(zero, |acc, v| acc + v)
+            }
+        }
+
+        impl<'a, T: Copy + num_traits::Zero> std::iter::Sum<&'a $vec<T>> for $vec<T> {
+            fn sum<I: Iterator<Item = &'a $vec<T>>>(iter: I) -> $vec<T> {
+                let zero = $vec {
+                    $($field: T::zero()),+
+                };
+                iter.fold(zero, |acc, v| &acc + v)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec_ops {
+    ($vec:ident { $($field:ident),+ }) => {
+        impl_component_op!($vec { $($field),+ }, Add, add, +);
+        impl_component_op!($vec { $($field),+ }, Sub, sub, -);
+        impl_component_op!($vec { $($field),+ }, Mul, mul, *);
+        impl_component_op!($vec { $($field),+ }, Div, div, /);
+
+        impl_scalar_op!($vec { $($field),+ }, Mul, mul, *);
+        impl_scalar_op!($vec { $($field),+ }, Div, div, /);
+
+        impl_component_assign_op!($vec { $($field),+ }, AddAssign, add_assign, +=);
+        impl_component_assign_op!($vec { $($field),+ }, SubAssign, sub_assign, -=);
+        impl_component_assign_op!($vec { $($field),+ }, MulAssign, mul_assign, *=);
+        impl_component_assign_op!($vec { $($field),+ }, DivAssign, div_assign, /=);
+
+        impl_scalar_assign_op!($vec { $($field),+ }, MulAssign, mul_assign, *=);
+        impl_scalar_assign_op!($vec { $($field),+ }, DivAssign, div_assign, /=);
+
+        impl_neg!($vec { $($field),+ });
+        impl_sum!($vec { $($field),+ });
+    };
+}
+
+// A blanket `impl<T> From<T>` would overlap with the tuple conversions, so the
+// broadcast constructor is spelled out per primitive element type.
+macro_rules! impl_broadcast_from {
+    ($vec:ident $fields:tt; $($t:ty),+) => {
+        $(impl_broadcast_from!(@one $vec $fields $t);)+
+    };
+    (@one $vec:ident { $($field:ident),+ } $t:ty) => {
+        impl From<$t> for $vec<$t> {
+            fn from(val: $t) -> Self {
+                $vec {
+                    $($field: val),+
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec_from_primitives {
+    ($vec:ident $fields:tt) => {
+        impl_broadcast_from!(
+            $vec $fields;
+            f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+        );
+    };
+}
@@ -1,5 +1,4 @@
-mod vec3;
-use vec3::Vec3;
+use cg_rust::vec3::Vec3;
 
 fn main() {
     let vec3: Vec3<f32> = Vec3::new();
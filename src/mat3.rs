@@ -0,0 +1,195 @@
+use crate::vec3::Vec3;
+use num_traits::Float;
+use std::ops::Mul;
+
+/// A 3x3 matrix stored in column-major order: `cols[c][r]` is the element in
+/// row `r` of column `c`, following the same conventions as `Mat4`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3<T> {
+    pub cols: [[T; 3]; 3],
+}
+
+impl<T: Float> Mat3<T> {
+    pub fn identity() -> Self {
+        let (o, l) = (T::zero(), T::one());
+        Mat3 {
+            cols: [[l, o, o], [o, l, o], [o, o, l]],
+        }
+    }
+
+    pub fn from_scale(s: Vec3<T>) -> Self {
+        let mut m = Mat3::identity();
+        m.cols[0][0] = s.x;
+        m.cols[1][1] = s.y;
+        m.cols[2][2] = s.z;
+        m
+    }
+
+    pub fn transpose(&self) -> Mat3<T> {
+        let mut cols = self.cols;
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, elem) in col.iter_mut().enumerate() {
+                *elem = self.cols[r][c];
+            }
+        }
+        Mat3 { cols }
+    }
+
+    pub fn determinant(&self) -> T {
+        let [a, b, c] = self.columns();
+        a.dot(&b.cross(&c))
+    }
+
+    /// Returns `None` when the matrix is singular. As with `Mat4::inverse`,
+    /// the determinant is compared against the product of the column lengths,
+    /// so small uniform scales still invert.
+    pub fn inverse(&self) -> Option<Mat3<T>> {
+        let det = self.determinant();
+        let [a, b, c] = self.columns();
+        if det.abs() <= T::epsilon() * a.length() * b.length() * c.length() {
+            return None;
+        }
+        let inv = T::one() / det;
+        let cof = self.cofactor();
+        let mut cols = cof.cols;
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, elem) in col.iter_mut().enumerate() {
+                *elem = cof.cols[r][c] * inv;
+            }
+        }
+        Some(Mat3 { cols })
+    }
+
+    /// The cofactor matrix, equal to `det * inverse().transpose()`. Its
+    /// columns are cross products of the other two columns.
+    pub(crate) fn cofactor(&self) -> Mat3<T> {
+        let [a, b, c] = self.columns();
+        let col = |v: Vec3<T>| [v.x, v.y, v.z];
+        Mat3 {
+            cols: [col(b.cross(&c)), col(c.cross(&a)), col(a.cross(&b))],
+        }
+    }
+
+    fn columns(&self) -> [Vec3<T>; 3] {
+        self.cols.map(Vec3::from)
+    }
+}
+
+impl<T: Float> Mul for Mat3<T> {
+    type Output = Mat3<T>;
+    fn mul(self, other: Mat3<T>) -> Mat3<T> {
+        let mut cols = [[T::zero(); 3]; 3];
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, elem) in col.iter_mut().enumerate() {
+                let mut sum = T::zero();
+                for k in 0..3 {
+                    sum = sum + self.cols[k][r] * other.cols[c][k];
+                }
+                *elem = sum;
+            }
+        }
+        Mat3 { cols }
+    }
+}
+
+impl<T: Float> Mul<Vec3<T>> for Mat3<T> {
+    type Output = Vec3<T>;
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        let m = &self.cols;
+        let row = |r: usize| m[0][r] * v.x + m[1][r] * v.y + m[2][r] * v.z;
+        Vec3 {
+            x: row(0),
+            y: row(1),
+            z: row(2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Mat3<f32> {
+        Mat3 {
+            cols: [[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]],
+        }
+    }
+
+    fn assert_mat3_near(a: Mat3<f32>, b: Mat3<f32>) {
+        for c in 0..3 {
+            for r in 0..3 {
+                assert!((a.cols[c][r] - b.cols[c][r]).abs() < 1e-5, "{a:?} != {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn identity_times_matrix_is_matrix() {
+        let m = sample();
+        assert_eq!(Mat3::identity() * m, m);
+        assert_eq!(m * Mat3::identity(), m);
+    }
+
+    #[test]
+    fn matrix_vector_uses_columns() {
+        let e1: Vec3<f32> = (0.0, 1.0, 0.0).into();
+        assert_eq!(sample() * e1, Vec3::from((1.0, 3.0, 0.0)));
+    }
+
+    #[test]
+    fn product_composes_right_to_left() {
+        let a = sample();
+        let b = Mat3::from_scale(Vec3::from((1.0, 2.0, 3.0)));
+        let v: Vec3<f32> = (1.0, -1.0, 2.0).into();
+        assert_eq!((a * b) * v, a * (b * v));
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let t = sample().transpose();
+        assert_eq!(t.cols[0], [2.0, 1.0, 0.0]);
+        assert_eq!(t.transpose(), sample());
+    }
+
+    #[test]
+    fn determinant_known_value() {
+        assert_eq!(sample().determinant(), 25.0);
+        assert_eq!(Mat3::<f32>::identity().determinant(), 1.0);
+    }
+
+    #[test]
+    fn inverse_times_matrix_is_identity() {
+        let m = sample();
+        let inv = m.inverse().unwrap();
+        assert_mat3_near(m * inv, Mat3::identity());
+        assert_mat3_near(inv * m, Mat3::identity());
+    }
+
+    #[test]
+    fn inverse_of_singular_is_none() {
+        let flat: Mat3<f32> = Mat3::from_scale(Vec3::from((1.0, 0.0, 1.0)));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_of_small_uniform_scale() {
+        let m: Mat3<f32> = Mat3::from_scale(Vec3::splat(0.004));
+        let inv = m.inverse().unwrap();
+        assert!((inv.cols[0][0] - 250.0).abs() < 1e-3);
+        assert_mat3_near(m * inv, Mat3::identity());
+    }
+
+    #[test]
+    fn inverse_transpose_keeps_normal_perpendicular() {
+        // Squashing the plane x = y along x tilts its tangent; the plain
+        // matrix skews the normal with it but the inverse-transpose doesn't.
+        let m: Mat3<f32> = Mat3::from_scale(Vec3::from((4.0, 1.0, 1.0)));
+        let tangent: Vec3<f32> = (1.0, 1.0, 0.0).into();
+        let normal: Vec3<f32> = (1.0, -1.0, 0.0).into();
+
+        let t = m * tangent;
+        assert!((m * normal).dot(&t).abs() > 1.0);
+        let n = m.inverse().unwrap().transpose() * normal;
+        assert!(n.dot(&t).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,704 @@
+use crate::mat3::Mat3;
+use crate::vec3::Vec3;
+use crate::vec4::Vec4;
+use num_traits::Float;
+use std::ops::Mul;
+
+/// A 4x4 matrix stored in column-major order: `cols[c][r]` is the element in
+/// row `r` of column `c`, matching the layout OpenGL-style APIs expect.
+///
+/// Vectors are treated as columns, so `m * v` transforms `v` and `a * b`
+/// produces the transform that applies `b` first, then `a`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4<T> {
+    pub cols: [[T; 4]; 4],
+}
+
+impl<T: Float> Mat4<T> {
+    pub fn identity() -> Self {
+        let (o, l) = (T::zero(), T::one());
+        Mat4 {
+            cols: [[l, o, o, o], [o, l, o, o], [o, o, l, o], [o, o, o, l]],
+        }
+    }
+
+    pub fn from_translation(t: Vec3<T>) -> Self {
+        let mut m = Mat4::identity();
+        m.cols[3] = [t.x, t.y, t.z, T::one()];
+        m
+    }
+
+    pub fn from_scale(s: Vec3<T>) -> Self {
+        let mut m = Mat4::identity();
+        m.cols[0][0] = s.x;
+        m.cols[1][1] = s.y;
+        m.cols[2][2] = s.z;
+        m
+    }
+
+    // The rotations follow the right-hand rule: a positive `angle` (in
+    // radians) turns counter-clockwise when looking down the axis towards the
+    // origin, so rotating +X by 90 degrees about Z gives +Y.
+
+    pub fn from_rotation_x(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut m = Mat4::identity();
+        m.cols[1][1] = cos;
+        m.cols[1][2] = sin;
+        m.cols[2][1] = -sin;
+        m.cols[2][2] = cos;
+        m
+    }
+
+    pub fn from_rotation_y(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut m = Mat4::identity();
+        m.cols[0][0] = cos;
+        m.cols[0][2] = -sin;
+        m.cols[2][0] = sin;
+        m.cols[2][2] = cos;
+        m
+    }
+
+    pub fn from_rotation_z(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let mut m = Mat4::identity();
+        m.cols[0][0] = cos;
+        m.cols[0][1] = sin;
+        m.cols[1][0] = -sin;
+        m.cols[1][1] = cos;
+        m
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    /// Right-handed view matrix for a camera at `eye` looking towards
+    /// `target`: the eye moves to the origin, the view direction to -Z, and
+    /// `up` (projected to be perpendicular to the view direction) to +Y.
+    ///
+    /// If `up` is parallel to the view direction the camera's roll is
+    /// undefined, so whichever world axis is least aligned with the view
+    /// direction is used as `up` instead. If `eye == target` the camera is
+    /// treated as looking down -Z.
+    pub fn look_at(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let forward = (target - eye)
+            .try_normalize()
+            .unwrap_or(Vec3 { x: o, y: o, z: -l });
+
+        let side = forward.cross(&up).try_normalize().unwrap_or_else(|| {
+            let abs = forward.map(T::abs);
+            let fallback = if abs.x <= abs.y && abs.x <= abs.z {
+                Vec3 { x: l, y: o, z: o }
+            } else if abs.y <= abs.z {
+                Vec3 { x: o, y: l, z: o }
+            } else {
+                Vec3 { x: o, y: o, z: l }
+            };
+            forward.cross(&fallback).normalize()
+        });
+        let up = side.cross(&forward);
+
+        Mat4 {
+            cols: [
+                [side.x, up.x, -forward.x, o],
+                [side.y, up.y, -forward.y, o],
+                [side.z, up.z, -forward.z, o],
+                [-side.dot(&eye), -up.dot(&eye), forward.dot(&eye), l],
+            ],
+        }
+    }
+}
+
+// The projections are right-handed: the camera looks down -Z and `near` and
+// `far` are positive distances in front of it. The plain constructors map
+// depth to the OpenGL clip range of -1..1, while the `_zero_to_one` variants
+// target the 0..1 range used by Vulkan, Direct3D, Metal, and wgpu. Each one
+// returns `None` for a degenerate volume (e.g. `near == far`) instead of a
+// matrix full of infinities.
+impl<T: Float> Mat4<T> {
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Option<Self> {
+        let two = T::one() + T::one();
+        let mut m = Mat4::perspective_frustum(fovy, aspect, near, far)?;
+        m.cols[2][2] = (far + near) / (near - far);
+        m.cols[3][2] = two * far * near / (near - far);
+        Some(m)
+    }
+
+    pub fn perspective_zero_to_one(fovy: T, aspect: T, near: T, far: T) -> Option<Self> {
+        let mut m = Mat4::perspective_frustum(fovy, aspect, near, far)?;
+        m.cols[2][2] = far / (near - far);
+        m.cols[3][2] = near * far / (near - far);
+        Some(m)
+    }
+
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Option<Self> {
+        let two = T::one() + T::one();
+        let mut m = Mat4::orthographic_box(left, right, bottom, top, near, far)?;
+        m.cols[2][2] = -two / (far - near);
+        m.cols[3][2] = -(far + near) / (far - near);
+        Some(m)
+    }
+
+    pub fn orthographic_zero_to_one(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+    ) -> Option<Self> {
+        let mut m = Mat4::orthographic_box(left, right, bottom, top, near, far)?;
+        m.cols[2][2] = -T::one() / (far - near);
+        m.cols[3][2] = -near / (far - near);
+        Some(m)
+    }
+
+    // Shared x/y terms of the perspective projections; depth is filled in by
+    // the caller.
+    fn perspective_frustum(fovy: T, aspect: T, near: T, far: T) -> Option<Self> {
+        let two = T::one() + T::one();
+        let tan_half = (fovy / two).tan();
+        if near == far || aspect == T::zero() || tan_half == T::zero() {
+            return None;
+        }
+        let f = T::one() / tan_half;
+        let mut m = Mat4 {
+            cols: [[T::zero(); 4]; 4],
+        };
+        m.cols[0][0] = f / aspect;
+        m.cols[1][1] = f;
+        m.cols[2][3] = -T::one();
+        Some(m)
+    }
+
+    // Shared x/y terms of the orthographic projections; depth is filled in by
+    // the caller.
+    fn orthographic_box(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Option<Self> {
+        if left == right || bottom == top || near == far {
+            return None;
+        }
+        let two = T::one() + T::one();
+        let mut m = Mat4::identity();
+        m.cols[0][0] = two / (right - left);
+        m.cols[1][1] = two / (top - bottom);
+        m.cols[3][0] = -(right + left) / (right - left);
+        m.cols[3][1] = -(top + bottom) / (top - bottom);
+        Some(m)
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    pub fn transpose(&self) -> Mat4<T> {
+        let mut cols = self.cols;
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, elem) in col.iter_mut().enumerate() {
+                *elem = self.cols[r][c];
+            }
+        }
+        Mat4 { cols }
+    }
+
+    pub fn determinant(&self) -> T {
+        Mat4::determinant_from_minors(&self.minors())
+    }
+
+    /// Inverse by cofactor expansion, or `None` when the matrix is singular
+    /// and the result would be dominated by rounding.
+    ///
+    /// `|det|` is bounded by the product of the column lengths (Hadamard's
+    /// inequality), so the determinant is compared against that product
+    /// rather than a fixed epsilon. Uniformly small or large scales still
+    /// invert, and only columns that are (nearly) linearly dependent give
+    /// `None`.
+    pub fn inverse(&self) -> Option<Mat4<T>> {
+        let b = self.minors();
+        let det = Mat4::determinant_from_minors(&b);
+        let column_lengths = self.cols.iter().fold(T::one(), |acc, col| {
+            acc * col.iter().fold(T::zero(), |sum, &e| sum + e * e).sqrt()
+        });
+        if det.abs() <= T::epsilon() * column_lengths {
+            return None;
+        }
+        let inv = T::one() / det;
+
+        let [
+            [a00, a01, a02, a03],
+            [a10, a11, a12, a13],
+            [a20, a21, a22, a23],
+            [a30, a31, a32, a33],
+        ] = self.cols;
+        Some(Mat4 {
+            cols: [
+                [
+                    (a11 * b[11] - a12 * b[10] + a13 * b[9]) * inv,
+                    (a02 * b[10] - a01 * b[11] - a03 * b[9]) * inv,
+                    (a31 * b[5] - a32 * b[4] + a33 * b[3]) * inv,
+                    (a22 * b[4] - a21 * b[5] - a23 * b[3]) * inv,
+                ],
+                [
+                    (a12 * b[8] - a10 * b[11] - a13 * b[7]) * inv,
+                    (a00 * b[11] - a02 * b[8] + a03 * b[7]) * inv,
+                    (a32 * b[2] - a30 * b[5] - a33 * b[1]) * inv,
+                    (a20 * b[5] - a22 * b[2] + a23 * b[1]) * inv,
+                ],
+                [
+                    (a10 * b[10] - a11 * b[8] + a13 * b[6]) * inv,
+                    (a01 * b[8] - a00 * b[10] - a03 * b[6]) * inv,
+                    (a30 * b[4] - a31 * b[2] + a33 * b[0]) * inv,
+                    (a21 * b[2] - a20 * b[4] - a23 * b[0]) * inv,
+                ],
+                [
+                    (a11 * b[7] - a10 * b[9] - a12 * b[6]) * inv,
+                    (a00 * b[9] - a01 * b[7] + a02 * b[6]) * inv,
+                    (a31 * b[1] - a30 * b[3] - a32 * b[0]) * inv,
+                    (a20 * b[3] - a21 * b[1] + a22 * b[0]) * inv,
+                ],
+            ],
+        })
+    }
+
+    // The twelve 2x2 determinants from the first two and last two columns,
+    // shared by `determinant` and `inverse`.
+    fn minors(&self) -> [T; 12] {
+        let [
+            [a00, a01, a02, a03],
+            [a10, a11, a12, a13],
+            [a20, a21, a22, a23],
+            [a30, a31, a32, a33],
+        ] = self.cols;
+        [
+            a00 * a11 - a01 * a10,
+            a00 * a12 - a02 * a10,
+            a00 * a13 - a03 * a10,
+            a01 * a12 - a02 * a11,
+            a01 * a13 - a03 * a11,
+            a02 * a13 - a03 * a12,
+            a20 * a31 - a21 * a30,
+            a20 * a32 - a22 * a30,
+            a20 * a33 - a23 * a30,
+            a21 * a32 - a22 * a31,
+            a21 * a33 - a23 * a31,
+            a22 * a33 - a23 * a32,
+        ]
+    }
+
+    fn determinant_from_minors(b: &[T; 12]) -> T {
+        b[0] * b[11] - b[1] * b[10] + b[2] * b[9] + b[3] * b[8] - b[4] * b[7] + b[5] * b[6]
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    /// The upper-left 3x3 block, i.e. the linear part of an affine transform.
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let c = &self.cols;
+        Mat3 {
+            cols: [
+                [c[0][0], c[0][1], c[0][2]],
+                [c[1][0], c[1][1], c[1][2]],
+                [c[2][0], c[2][1], c[2][2]],
+            ],
+        }
+    }
+
+    /// The inverse-transpose of the upper-left 3x3 block, which maps surface
+    /// normals so they stay perpendicular under non-uniform scale.
+    ///
+    /// A singular block has no inverse; it then falls back to the cofactor
+    /// matrix, which is the inverse-transpose up to scale and so still gives
+    /// correct normal directions wherever one exists. Normalize the results
+    /// either way.
+    pub fn normal_matrix(&self) -> Mat3<T> {
+        let m = self.to_mat3();
+        match m.inverse() {
+            Some(inv) => inv.transpose(),
+            None => m.cofactor(),
+        }
+    }
+}
+
+impl<T: Float> Mul for Mat4<T> {
+    type Output = Mat4<T>;
+    fn mul(self, other: Mat4<T>) -> Mat4<T> {
+        let mut cols = [[T::zero(); 4]; 4];
+        for (c, col) in cols.iter_mut().enumerate() {
+            for (r, elem) in col.iter_mut().enumerate() {
+                let mut sum = T::zero();
+                for k in 0..4 {
+                    sum = sum + self.cols[k][r] * other.cols[c][k];
+                }
+                *elem = sum;
+            }
+        }
+        Mat4 { cols }
+    }
+}
+
+impl<T: Float> Mul<Vec4<T>> for Mat4<T> {
+    type Output = Vec4<T>;
+    fn mul(self, v: Vec4<T>) -> Vec4<T> {
+        let m = &self.cols;
+        let row = |r: usize| m[0][r] * v.x + m[1][r] * v.y + m[2][r] * v.z + m[3][r] * v.w;
+        Vec4 {
+            x: row(0),
+            y: row(1),
+            z: row(2),
+            w: row(3),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Mat4<f32> {
+        Mat4 {
+            cols: [
+                [1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0, 12.0],
+                [13.0, 14.0, 15.0, 16.0],
+            ],
+        }
+    }
+
+    #[test]
+    fn identity_diagonal() {
+        let m: Mat4<f32> = Mat4::identity();
+        for c in 0..4 {
+            for r in 0..4 {
+                assert_eq!(m.cols[c][r], if c == r { 1.0 } else { 0.0 });
+            }
+        }
+    }
+
+    #[test]
+    fn identity_times_matrix_is_matrix() {
+        let m = sample();
+        assert_eq!(Mat4::identity() * m, m);
+        assert_eq!(m * Mat4::identity(), m);
+    }
+
+    #[test]
+    fn identity_times_vector_is_vector() {
+        let v: Vec4<f32> = (1.0, 2.0, 3.0, 1.0).into();
+        assert_eq!(Mat4::identity() * v, v);
+    }
+
+    #[test]
+    fn scale_matrix_scales_vector() {
+        let mut scale: Mat4<f32> = Mat4::identity();
+        scale.cols[0][0] = 2.0;
+        scale.cols[1][1] = 2.0;
+        scale.cols[2][2] = 2.0;
+
+        let v: Vec4<f32> = (1.0, 2.0, 3.0, 1.0).into();
+        assert_eq!(scale * v, Vec4::from((2.0, 4.0, 6.0, 1.0)));
+    }
+
+    #[test]
+    fn matrix_vector_uses_columns() {
+        // Picking out one basis vector returns the matching column.
+        let m = sample();
+        let e1: Vec4<f32> = (0.0, 1.0, 0.0, 0.0).into();
+        assert_eq!(m * e1, Vec4::from((5.0, 6.0, 7.0, 8.0)));
+    }
+
+    #[test]
+    fn matrix_product_known_values() {
+        // With column vectors, (a * b) * v == a * (b * v).
+        let a = sample();
+        let mut b: Mat4<f32> = Mat4::identity();
+        b.cols[3] = [1.0, 2.0, 3.0, 1.0];
+        let v: Vec4<f32> = (1.0, 1.0, 1.0, 1.0).into();
+        assert_eq!((a * b) * v, a * (b * v));
+
+        let product = a * b;
+        assert_eq!(product.cols[0], a.cols[0]);
+        assert_eq!(product.cols[3], [51.0, 58.0, 65.0, 72.0]);
+    }
+
+    // ============ Transform Constructor Tests ============
+
+    fn assert_vec4_near(a: Vec4<f32>, b: Vec4<f32>) {
+        assert!((a.x - b.x).abs() < 1e-6, "{:?} != {:?}", a, b);
+        assert!((a.y - b.y).abs() < 1e-6, "{:?} != {:?}", a, b);
+        assert!((a.z - b.z).abs() < 1e-6, "{:?} != {:?}", a, b);
+        assert!((a.w - b.w).abs() < 1e-6, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn translate_origin() {
+        let m = Mat4::from_translation(Vec3::from((1.0, 2.0, 3.0)));
+        let origin: Vec4<f32> = (0.0, 0.0, 0.0, 1.0).into();
+        assert_eq!(m * origin, Vec4::from((1.0, 2.0, 3.0, 1.0)));
+    }
+
+    #[test]
+    fn translate_ignores_directions() {
+        let m = Mat4::from_translation(Vec3::from((1.0, 2.0, 3.0)));
+        let dir: Vec4<f32> = (0.0, 1.0, 0.0, 0.0).into();
+        assert_eq!(m * dir, dir);
+    }
+
+    #[test]
+    fn scale_components() {
+        let m = Mat4::from_scale(Vec3::from((2.0, 3.0, 4.0)));
+        let v: Vec4<f32> = (1.0, 1.0, 1.0, 1.0).into();
+        assert_eq!(m * v, Vec4::from((2.0, 3.0, 4.0, 1.0)));
+    }
+
+    #[test]
+    fn rotate_z_maps_x_to_y() {
+        let m = Mat4::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let x_axis: Vec4<f32> = (1.0, 0.0, 0.0, 0.0).into();
+        assert_vec4_near(m * x_axis, Vec4::from((0.0, 1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn rotate_x_maps_y_to_z() {
+        let m = Mat4::from_rotation_x(std::f32::consts::FRAC_PI_2);
+        let y_axis: Vec4<f32> = (0.0, 1.0, 0.0, 0.0).into();
+        assert_vec4_near(m * y_axis, Vec4::from((0.0, 0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn rotate_y_maps_z_to_x() {
+        let m = Mat4::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        let z_axis: Vec4<f32> = (0.0, 0.0, 1.0, 0.0).into();
+        assert_vec4_near(m * z_axis, Vec4::from((1.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn compose_scale_then_translate() {
+        // translate * scale scales first, so the offset itself is not scaled.
+        let translate = Mat4::from_translation(Vec3::from((1.0, 0.0, 0.0)));
+        let scale = Mat4::from_scale(Vec3::from((2.0, 2.0, 2.0)));
+        let p: Vec4<f32> = (1.0, 1.0, 1.0, 1.0).into();
+        assert_eq!((translate * scale) * p, Vec4::from((3.0, 2.0, 2.0, 1.0)));
+        assert_eq!((scale * translate) * p, Vec4::from((4.0, 2.0, 2.0, 1.0)));
+    }
+
+    // ============ Projection Tests ============
+
+    fn ndc_depth(m: Mat4<f32>, z: f32) -> f32 {
+        (m * Vec4::from((0.0, 0.0, z, 1.0))).perspective_divide().z
+    }
+
+    #[test]
+    fn perspective_near_and_far_planes() {
+        let m = Mat4::perspective(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0).unwrap();
+        assert!((ndc_depth(m, -1.0) + 1.0).abs() < 1e-6);
+        assert!((ndc_depth(m, -10.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn perspective_zero_to_one_near_and_far_planes() {
+        let m = Mat4::perspective_zero_to_one(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0).unwrap();
+        assert!(ndc_depth(m, -1.0).abs() < 1e-6);
+        assert!((ndc_depth(m, -10.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn perspective_edge_of_fov_maps_to_edge_of_ndc() {
+        // A 90 degree field of view reaches y = 1 on the near plane at z = -1.
+        let m = Mat4::perspective(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0).unwrap();
+        let ndc = (m * Vec4::from((0.0, 1.0, -1.0, 1.0))).perspective_divide();
+        assert!((ndc.y - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn perspective_degenerate_depth_is_none() {
+        assert!(Mat4::perspective(1.0f32, 1.0, 5.0, 5.0).is_none());
+        assert!(Mat4::perspective_zero_to_one(1.0f32, 1.0, 5.0, 5.0).is_none());
+    }
+
+    #[test]
+    fn orthographic_maps_box_to_ndc() {
+        let m = Mat4::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0).unwrap();
+        let corner = (m * Vec4::from((2.0, -1.0, -1.0, 1.0))).perspective_divide();
+        assert_eq!(corner, Vec3::from((1.0, -1.0, -1.0)));
+        assert!((ndc_depth(m, -10.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn orthographic_zero_to_one_depth() {
+        let m = Mat4::orthographic_zero_to_one(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0).unwrap();
+        assert!(ndc_depth(m, -1.0).abs() < 1e-6);
+        assert!((ndc_depth(m, -10.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn orthographic_degenerate_box_is_none() {
+        assert!(Mat4::orthographic(1.0f32, 1.0, -1.0, 1.0, 1.0, 10.0).is_none());
+        assert!(Mat4::orthographic(-1.0f32, 1.0, -1.0, 1.0, 3.0, 3.0).is_none());
+    }
+
+    // ============ Inverse/Transpose Tests ============
+
+    fn assert_mat4_near(a: Mat4<f32>, b: Mat4<f32>, epsilon: f32) {
+        for c in 0..4 {
+            for r in 0..4 {
+                assert!(
+                    (a.cols[c][r] - b.cols[c][r]).abs() < epsilon,
+                    "{:?} != {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let t = sample().transpose();
+        assert_eq!(t.cols[0], [1.0, 5.0, 9.0, 13.0]);
+        assert_eq!(t.cols[3], [4.0, 8.0, 12.0, 16.0]);
+        assert_eq!(t.transpose(), sample());
+    }
+
+    #[test]
+    fn determinant_known_values() {
+        let scale: Mat4<f32> = Mat4::from_scale(Vec3::from((2.0, 3.0, 4.0)));
+        assert_eq!(scale.determinant(), 24.0);
+        assert_eq!(Mat4::<f32>::identity().determinant(), 1.0);
+        // Columns of `sample` are linearly dependent.
+        assert_eq!(sample().determinant(), 0.0);
+    }
+
+    #[test]
+    fn inverse_of_composed_transform() {
+        let m = Mat4::from_translation(Vec3::from((1.0, -2.0, 3.0)))
+            * Mat4::from_rotation_y(0.7)
+            * Mat4::from_rotation_x(-0.3)
+            * Mat4::from_scale(Vec3::from((2.0, 0.5, 3.0)));
+        let inv = m.inverse().unwrap();
+        assert_mat4_near(m * inv, Mat4::identity(), 1e-5);
+        assert_mat4_near(inv * m, Mat4::identity(), 1e-5);
+    }
+
+    #[test]
+    fn inverse_of_translation_negates_offset() {
+        let m: Mat4<f32> = Mat4::from_translation(Vec3::from((1.0, 2.0, 3.0)));
+        let expected = Mat4::from_translation(Vec3::from((-1.0, -2.0, -3.0)));
+        assert_eq!(m.inverse(), Some(expected));
+    }
+
+    #[test]
+    fn inverse_of_singular_is_none() {
+        assert_eq!(sample().inverse(), None);
+        let flat: Mat4<f32> = Mat4::from_scale(Vec3::from((1.0, 0.0, 1.0)));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_of_small_uniform_scale() {
+        let m: Mat4<f32> = Mat4::from_scale(Vec3::splat(0.004));
+        let inv = m.inverse().unwrap();
+        assert_mat4_near(inv, Mat4::from_scale(Vec3::splat(250.0)), 1e-3);
+        assert_mat4_near(m * inv, Mat4::identity(), 1e-5);
+    }
+
+    #[test]
+    fn inverse_of_nearly_dependent_columns_is_none() {
+        let mut m: Mat4<f32> = Mat4::identity();
+        m.cols[1] = [1.0, 1e-9, 0.0, 0.0];
+        assert_eq!(m.inverse(), None);
+    }
+
+    // ============ View Matrix Tests ============
+
+    fn transform_point(m: Mat4<f32>, p: Vec3<f32>) -> Vec3<f32> {
+        (m * Vec4::from((p, 1.0))).perspective_divide()
+    }
+
+    #[test]
+    fn look_at_places_target_down_negative_z() {
+        let eye: Vec3<f32> = (0.0, 0.0, 5.0).into();
+        let view = Mat4::look_at(eye, Vec3::new(), Vec3::from((0.0, 1.0, 0.0)));
+        assert_eq!(transform_point(view, eye), Vec3::new());
+        assert_eq!(
+            transform_point(view, Vec3::new()),
+            Vec3::from((0.0, 0.0, -5.0))
+        );
+        assert_eq!(
+            transform_point(view, Vec3::from((0.0, 1.0, 0.0))),
+            Vec3::from((0.0, 1.0, -5.0))
+        );
+    }
+
+    #[test]
+    fn look_at_off_axis_camera() {
+        let eye: Vec3<f32> = (3.0, 4.0, -2.0).into();
+        let target: Vec3<f32> = (1.0, 0.0, 2.0).into();
+        let view = Mat4::look_at(eye, target, Vec3::from((0.0, 1.0, 0.0)));
+        let distance = eye.distance(&target);
+        assert!(transform_point(view, eye).abs_diff_eq(&Vec3::new(), 1e-5));
+        assert!(
+            transform_point(view, target).abs_diff_eq(&Vec3::from((0.0, 0.0, -distance)), 1e-5)
+        );
+        assert!((view.determinant() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn look_at_with_parallel_up_stays_orthonormal() {
+        let eye: Vec3<f32> = (0.0, 5.0, 0.0).into();
+        let view = Mat4::look_at(eye, Vec3::new(), Vec3::from((0.0, 1.0, 0.0)));
+        assert!(view.cols.iter().flatten().all(|c| c.is_finite()));
+        assert!((view.determinant() - 1.0).abs() < 1e-5);
+        assert!(
+            transform_point(view, Vec3::new()).abs_diff_eq(&Vec3::from((0.0, 0.0, -5.0)), 1e-5)
+        );
+    }
+
+    // ============ Normal Matrix Tests ============
+
+    #[test]
+    fn to_mat3_takes_upper_left_block() {
+        let m = sample().to_mat3();
+        assert_eq!(m.cols[0], [1.0, 2.0, 3.0]);
+        assert_eq!(m.cols[2], [9.0, 10.0, 11.0]);
+    }
+
+    #[test]
+    fn normal_matrix_unskews_non_uniform_scale() {
+        let m: Mat4<f32> = Mat4::from_translation(Vec3::from((5.0, 0.0, 0.0)))
+            * Mat4::from_rotation_z(0.3)
+            * Mat4::from_scale(Vec3::from((3.0, 1.0, 0.5)));
+        let tangent: Vec3<f32> = (1.0, 2.0, 0.0).into();
+        let normal: Vec3<f32> = (2.0, -1.0, 0.0).into();
+
+        let t = m.to_mat3() * tangent;
+        let n = m.normal_matrix() * normal;
+        assert!(n.dot(&t).abs() < 1e-5);
+    }
+
+    #[test]
+    fn normal_matrix_of_rotation_is_rotation() {
+        let m: Mat4<f32> = Mat4::from_rotation_y(0.7);
+        let (a, b) = (m.normal_matrix(), m.to_mat3());
+        for c in 0..3 {
+            for r in 0..3 {
+                assert!((a.cols[c][r] - b.cols[c][r]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn normal_matrix_of_small_uniform_scale_is_inverse_transpose() {
+        // Uniform scale 0.004 must invert rather than fall back to the
+        // cofactor matrix, which would be smaller by a factor of det.
+        let m: Mat4<f32> = Mat4::from_scale(Vec3::splat(0.004));
+        let n = m.normal_matrix() * Vec3::from((0.0, 1.0, 0.0));
+        assert!(n.abs_diff_eq(&Vec3::from((0.0, 250.0, 0.0)), 1e-3));
+    }
+
+    #[test]
+    fn normal_matrix_of_flattening_scale_is_finite() {
+        let m: Mat4<f32> = Mat4::from_scale(Vec3::from((1.0, 0.0, 1.0)));
+        let n = m.normal_matrix() * Vec3::from((0.0, 1.0, 0.0));
+        assert!(n.x.is_finite() && n.y.is_finite() && n.z.is_finite());
+        assert_ne!(n, Vec3::new());
+    }
+}
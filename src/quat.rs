@@ -0,0 +1,336 @@
+use crate::mat4::Mat4;
+use crate::vec3::Vec3;
+use num_traits::Float;
+use std::ops::Mul;
+
+/// A rotation quaternion with vector part `(x, y, z)` and scalar part `w`.
+///
+/// Rotations follow the same right-hand rule as `Mat4::from_rotation_*`, and
+/// `a * b` applies `b` first, then `a`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quat<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Float> Quat<T> {
+    pub fn identity() -> Self {
+        Quat {
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+            w: T::one(),
+        }
+    }
+
+    /// Rotation of `angle` radians about `axis`. The axis is normalized here,
+    /// so it only needs to point in the right direction.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Self {
+        let axis = axis.normalize();
+        let (sin, cos) = (angle / (T::one() + T::one())).sin_cos();
+        Quat {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: cos,
+        }
+    }
+
+    /// Returns the unit quaternion. A zero quaternion is returned unchanged
+    /// rather than turning into NaNs.
+    pub fn normalize(&self) -> Quat<T> {
+        let len_sq = self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w;
+        if len_sq > T::zero() {
+            let len = len_sq.sqrt();
+            Quat {
+                x: self.x / len,
+                y: self.y / len,
+                z: self.z / len,
+                w: self.w / len,
+            }
+        } else {
+            *self
+        }
+    }
+
+    pub fn dot(&self, other: &Quat<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Spherical linear interpolation between two unit quaternions, taking the
+    /// shorter of the two arcs. Nearly parallel inputs fall back to a
+    /// normalized lerp, where the slerp weights would divide by ~0.
+    ///
+    /// `t = 0` and `t = 1` return `self` and `other` exactly, even when the
+    /// shorter arc runs towards `-other`.
+    pub fn slerp(&self, other: &Quat<T>, t: T) -> Quat<T> {
+        if t == T::zero() {
+            return *self;
+        }
+        if t == T::one() {
+            return *other;
+        }
+
+        let mut cos = self.dot(other);
+        let mut end = *other;
+        if cos < T::zero() {
+            // q and -q are the same rotation; flip to take the short way round.
+            cos = -cos;
+            end = Quat {
+                x: -end.x,
+                y: -end.y,
+                z: -end.z,
+                w: -end.w,
+            };
+        }
+
+        let one = T::one();
+        let threshold = one - T::from(1e-6).unwrap();
+        if cos > threshold {
+            return self.weighted_sum(one - t, &end, t).normalize();
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let a = ((one - t) * theta).sin() / sin;
+        let b = (t * theta).sin() / sin;
+        self.weighted_sum(a, &end, b)
+    }
+
+    fn weighted_sum(&self, a: T, other: &Quat<T>, b: T) -> Quat<T> {
+        Quat {
+            x: self.x * a + other.x * b,
+            y: self.y * a + other.y * b,
+            z: self.z * a + other.z * b,
+            w: self.w * a + other.w * b,
+        }
+    }
+
+    /// Negates the vector part. For a unit quaternion this is the inverse
+    /// rotation.
+    pub fn conjugate(&self) -> Quat<T> {
+        Quat {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Rotates `v`, assuming `self` is a unit quaternion.
+    pub fn rotate(&self, v: Vec3<T>) -> Vec3<T> {
+        // Expanded form of q * v * q^-1 that skips the full products.
+        let two = T::one() + T::one();
+        let u = Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let t = u.cross(&v) * two;
+        v + t * self.w + u.cross(&t)
+    }
+
+    /// Rotation matrix equivalent to `rotate`, assuming a unit quaternion.
+    pub fn to_mat4(&self) -> Mat4<T> {
+        let (o, l) = (T::zero(), T::one());
+        let two = l + l;
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        Mat4 {
+            cols: [
+                [
+                    l - two * (y * y + z * z),
+                    two * (x * y + w * z),
+                    two * (x * z - w * y),
+                    o,
+                ],
+                [
+                    two * (x * y - w * z),
+                    l - two * (x * x + z * z),
+                    two * (y * z + w * x),
+                    o,
+                ],
+                [
+                    two * (x * z + w * y),
+                    two * (y * z - w * x),
+                    l - two * (x * x + y * y),
+                    o,
+                ],
+                [o, o, o, l],
+            ],
+        }
+    }
+}
+
+impl<T: Float> Mul for Quat<T> {
+    type Output = Quat<T>;
+    fn mul(self, other: Quat<T>) -> Quat<T> {
+        Quat {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec4::Vec4;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_vec3_near(a: Vec3<f32>, b: Vec3<f32>) {
+        assert!(a.abs_diff_eq(&b, 1e-6), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn identity_leaves_vector_unchanged() {
+        let q: Quat<f32> = Quat::identity();
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        assert_eq!(q.rotate(v), v);
+    }
+
+    #[test]
+    fn rotate_z_maps_x_to_y() {
+        let q = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), FRAC_PI_2);
+        assert_vec3_near(
+            q.rotate(Vec3::from((1.0, 0.0, 0.0))),
+            Vec3::from((0.0, 1.0, 0.0)),
+        );
+    }
+
+    #[test]
+    fn rotate_matches_rotation_matrix() {
+        let q = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), FRAC_PI_2);
+        let m = Mat4::from_rotation_z(FRAC_PI_2);
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let expected = (m * Vec4::from((v, 0.0))).xyz();
+        assert_vec3_near(q.rotate(v), expected);
+    }
+
+    #[test]
+    fn to_mat4_matches_rotate() {
+        let q = Quat::from_axis_angle(Vec3::from((1.0, 1.0, 0.0)), 0.7);
+        let v: Vec3<f32> = (0.5, -2.0, 1.5).into();
+        let rotated = (q.to_mat4() * Vec4::from((v, 0.0))).xyz();
+        assert_vec3_near(rotated, q.rotate(v));
+    }
+
+    #[test]
+    fn to_mat4_matches_axis_rotations() {
+        let cases = [
+            (Vec3::from((1.0, 0.0, 0.0)), Mat4::from_rotation_x(0.3)),
+            (Vec3::from((0.0, 1.0, 0.0)), Mat4::from_rotation_y(0.3)),
+            (Vec3::from((0.0, 0.0, 1.0)), Mat4::from_rotation_z(0.3)),
+        ];
+        for (axis, expected) in cases {
+            let m = Quat::from_axis_angle(axis, 0.3).to_mat4();
+            for c in 0..4 {
+                for r in 0..4 {
+                    assert!((m.cols[c][r] - expected.cols[c][r]).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn multiply_composes_rotations() {
+        // Two 45 degree turns about Z make a 90 degree turn.
+        let half = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), FRAC_PI_2 / 2.0);
+        let q = half * half;
+        assert_vec3_near(
+            q.rotate(Vec3::from((1.0, 0.0, 0.0))),
+            Vec3::from((0.0, 1.0, 0.0)),
+        );
+    }
+
+    #[test]
+    fn multiply_applies_right_operand_first() {
+        let about_x = Quat::from_axis_angle(Vec3::from((1.0, 0.0, 0.0)), FRAC_PI_2);
+        let about_z = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), FRAC_PI_2);
+        let v: Vec3<f32> = (0.0, 1.0, 0.0).into();
+        assert_vec3_near(
+            (about_z * about_x).rotate(v),
+            about_z.rotate(about_x.rotate(v)),
+        );
+    }
+
+    #[test]
+    fn conjugate_undoes_rotation() {
+        let q = Quat::from_axis_angle(Vec3::from((1.0, 2.0, 3.0)), 1.2);
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        assert_vec3_near(q.conjugate().rotate(q.rotate(v)), v);
+    }
+
+    #[test]
+    fn normalize_gives_unit_quaternion() {
+        let q: Quat<f64> = Quat {
+            x: 1.0,
+            y: 2.0,
+            z: 2.0,
+            w: 4.0,
+        };
+        let n = q.normalize();
+        assert_eq!(
+            n,
+            Quat {
+                x: 0.2,
+                y: 0.4,
+                z: 0.4,
+                w: 0.8,
+            }
+        );
+    }
+
+    // ============ Slerp Tests ============
+
+    #[test]
+    fn slerp_same_quaternion() {
+        let q = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), 1.0f32);
+        assert_eq!(q.slerp(&q, 0.5), q);
+    }
+
+    #[test]
+    fn slerp_endpoints() {
+        let a = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), 0.2f32);
+        let b = Quat::from_axis_angle(Vec3::from((0.0, 1.0, 0.0)), 1.4f32);
+        assert_eq!(a.slerp(&b, 0.0), a);
+        assert_eq!(a.slerp(&b, 1.0), b);
+
+        // A negative dot product flips to the shorter arc but must still end
+        // on `other` itself, not `-other`.
+        let far = Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), 4.0f32);
+        assert!(a.dot(&far) < 0.0);
+        assert_eq!(a.slerp(&far, 0.0), a);
+        assert_eq!(a.slerp(&far, 1.0), far);
+    }
+
+    #[test]
+    fn slerp_halfway_is_half_angle() {
+        let axis: Vec3<f32> = (0.0, 0.0, 1.0).into();
+        let a: Quat<f32> = Quat::identity();
+        let b = Quat::from_axis_angle(axis, FRAC_PI_2);
+        let mid = a.slerp(&b, 0.5);
+        let expected = Quat::from_axis_angle(axis, FRAC_PI_2 / 2.0);
+        assert!((mid.dot(&expected) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn slerp_takes_shortest_path() {
+        // -b is the same rotation as b, so the midpoint must not swing the
+        // long way round.
+        let axis: Vec3<f32> = (0.0, 0.0, 1.0).into();
+        let a: Quat<f32> = Quat::identity();
+        let b = Quat::from_axis_angle(axis, FRAC_PI_2);
+        let neg_b = Quat {
+            x: -b.x,
+            y: -b.y,
+            z: -b.z,
+            w: -b.w,
+        };
+        let v: Vec3<f32> = (1.0, 0.0, 0.0).into();
+        assert_vec3_near(a.slerp(&neg_b, 0.5).rotate(v), a.slerp(&b, 0.5).rotate(v));
+    }
+}
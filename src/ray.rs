@@ -0,0 +1,93 @@
+use crate::vec3::Vec3;
+use num_traits::Float;
+
+/// A half-line starting at `origin` and extending along `dir`. `dir` does not
+/// need to be normalized; hit parameters are in units of its length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray<T> {
+    pub origin: Vec3<T>,
+    pub dir: Vec3<T>,
+}
+
+impl<T: Float> Ray<T> {
+    pub fn new(origin: Vec3<T>, dir: Vec3<T>) -> Self {
+        Ray { origin, dir }
+    }
+
+    /// Point at parameter `t` along the ray.
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.origin + self.dir * t
+    }
+
+    /// Parameter of the nearest intersection in front of the origin, or `None`
+    /// if the ray misses or the sphere is entirely behind it. A ray starting
+    /// inside the sphere reports where it exits.
+    pub fn intersect_sphere(&self, center: Vec3<T>, radius: T) -> Option<T> {
+        let oc = self.origin - center;
+        let a = self.dir.length_squared();
+        let half_b = oc.dot(&self.dir);
+        let c = oc.length_squared() - radius * radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < T::zero() || a == T::zero() {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let near = (-half_b - sqrt_d) / a;
+        if near > T::zero() {
+            return Some(near);
+        }
+        let far = (-half_b + sqrt_d) / a;
+        if far > T::zero() { Some(far) } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_walks_along_direction() {
+        let ray = Ray::new(Vec3::from((1.0, 0.0, 0.0)), Vec3::from((0.0, 2.0, 0.0)));
+        assert_eq!(ray.at(0.0), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(ray.at(1.5), Vec3::from((1.0, 3.0, 0.0)));
+    }
+
+    #[test]
+    fn direct_hit_returns_near_surface() {
+        let ray: Ray<f32> = Ray::new(Vec3::new(), Vec3::from((0.0, 0.0, -1.0)));
+        let t = ray.intersect_sphere(Vec3::from((0.0, 0.0, -5.0)), 1.0);
+        assert_eq!(t, Some(4.0));
+    }
+
+    #[test]
+    fn unnormalized_direction_scales_parameter() {
+        let ray: Ray<f32> = Ray::new(Vec3::new(), Vec3::from((0.0, 0.0, -2.0)));
+        let t = ray.intersect_sphere(Vec3::from((0.0, 0.0, -5.0)), 1.0);
+        assert_eq!(t, Some(2.0));
+    }
+
+    #[test]
+    fn miss_returns_none() {
+        let ray: Ray<f32> = Ray::new(Vec3::new(), Vec3::from((0.0, 0.0, -1.0)));
+        assert_eq!(
+            ray.intersect_sphere(Vec3::from((0.0, 3.0, -5.0)), 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn sphere_behind_origin_returns_none() {
+        let ray: Ray<f32> = Ray::new(Vec3::new(), Vec3::from((0.0, 0.0, 1.0)));
+        assert_eq!(
+            ray.intersect_sphere(Vec3::from((0.0, 0.0, -5.0)), 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn origin_inside_sphere_hits_exit() {
+        let ray: Ray<f64> = Ray::new(Vec3::new(), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(ray.intersect_sphere(Vec3::new(), 2.0), Some(2.0));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::mat4::Mat4;
+use crate::quat::Quat;
+use crate::vec3::Vec3;
+use num_traits::Float;
+use std::ops::Mul;
+
+/// An affine transform that scales, then rotates, then translates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform<T> {
+    pub translation: Vec3<T>,
+    pub rotation: Quat<T>,
+    pub scale: Vec3<T>,
+}
+
+impl<T: Float> Transform<T> {
+    pub fn identity() -> Self {
+        Transform {
+            translation: Vec3::splat(0),
+            rotation: Quat::identity(),
+            scale: Vec3::splat(1),
+        }
+    }
+
+    /// The equivalent `translation * rotation * scale` matrix.
+    pub fn to_mat4(&self) -> Mat4<T> {
+        Mat4::from_translation(self.translation)
+            * self.rotation.to_mat4()
+            * Mat4::from_scale(self.scale)
+    }
+
+    pub fn transform_point(&self, p: Vec3<T>) -> Vec3<T> {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Applies scale and rotation but not translation, as suits directions
+    /// and offsets.
+    pub fn transform_vector(&self, v: Vec3<T>) -> Vec3<T> {
+        self.rotation.rotate(v * self.scale)
+    }
+}
+
+/// `a * b` applies `b` first, then `a`, matching matrix multiplication.
+///
+/// A rotation followed by a non-uniform scale produces shear, which a
+/// `Transform` can't represent. The result is exact when `a` has uniform
+/// scale; otherwise the scales are multiplied component-wise as an
+/// approximation.
+impl<T: Float> Mul for Transform<T> {
+    type Output = Transform<T>;
+    fn mul(self, other: Transform<T>) -> Transform<T> {
+        Transform {
+            translation: self.transform_point(other.translation),
+            rotation: self.rotation * other.rotation,
+            scale: self.scale * other.scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec4::Vec4;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn sample() -> Transform<f32> {
+        Transform {
+            translation: Vec3::from((1.0, 2.0, 3.0)),
+            rotation: Quat::from_axis_angle(Vec3::from((0.0, 0.0, 1.0)), FRAC_PI_2),
+            scale: Vec3::from((2.0, 3.0, 4.0)),
+        }
+    }
+
+    #[test]
+    fn identity_leaves_points_unchanged() {
+        let p: Vec3<f32> = (1.0, -2.0, 3.0).into();
+        assert_eq!(Transform::identity().transform_point(p), p);
+    }
+
+    #[test]
+    fn transform_point_scales_then_rotates_then_translates() {
+        // Scale (1, 0, 0) to (2, 0, 0), rotate to (0, 2, 0), then offset.
+        let p = sample().transform_point(Vec3::from((1.0, 0.0, 0.0)));
+        assert!(p.abs_diff_eq(&Vec3::from((1.0, 4.0, 3.0)), 1e-6));
+    }
+
+    #[test]
+    fn transform_vector_ignores_translation() {
+        let v = sample().transform_vector(Vec3::from((0.0, 1.0, 0.0)));
+        assert!(v.abs_diff_eq(&Vec3::from((-3.0, 0.0, 0.0)), 1e-6));
+    }
+
+    #[test]
+    fn to_mat4_matches_transform_point() {
+        let t = sample();
+        let p: Vec3<f32> = (0.5, -1.0, 2.0).into();
+        let via_matrix = (t.to_mat4() * Vec4::from((p, 1.0))).xyz();
+        assert!(via_matrix.abs_diff_eq(&t.transform_point(p), 1e-5));
+    }
+
+    #[test]
+    fn compose_matches_matrix_product() {
+        let a = Transform {
+            translation: Vec3::from((-1.0, 0.5, 2.0)),
+            rotation: Quat::from_axis_angle(Vec3::from((1.0, 1.0, 0.0)), 0.8),
+            scale: Vec3::splat(1.5),
+        };
+        let b = sample();
+        let composed = (a * b).to_mat4();
+        let product = a.to_mat4() * b.to_mat4();
+        for c in 0..4 {
+            for r in 0..4 {
+                assert!((composed.cols[c][r] - product.cols[c][r]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn compose_applies_right_operand_first() {
+        let a = Transform {
+            translation: Vec3::from((10.0, 0.0, 0.0)),
+            ..Transform::identity()
+        };
+        let b = sample();
+        let p: Vec3<f32> = (1.0, 1.0, 1.0).into();
+        let expected = a.transform_point(b.transform_point(p));
+        assert!((a * b).transform_point(p).abs_diff_eq(&expected, 1e-5));
+    }
+}
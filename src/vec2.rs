@@ -0,0 +1,142 @@
+use num_traits::{Float, NumCast, ToPrimitive};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl_vec_common!(Vec2 { x, y });
+impl_vec_ops!(Vec2 { x, y });
+impl_vec_from_primitives!(Vec2 { x, y });
+
+impl<T: Float, U: ToPrimitive> From<(U, U)> for Vec2<T> {
+    fn from((x, y): (U, U)) -> Self {
+        Vec2 {
+            x: NumCast::from(x).unwrap(),
+            y: NumCast::from(y).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::op_ref)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_zero_vec() {
+        let vec2: Vec2<f32> = Vec2::new();
+        assert_eq!(vec2.x, 0.0);
+        assert_eq!(vec2.y, 0.0);
+    }
+
+    #[test]
+    fn create_broadcast_vec() {
+        let vec2: Vec2<f64> = Vec2::splat(11);
+        assert_eq!(vec2.x, 11.0);
+        assert_eq!(vec2.y, 11.0);
+    }
+
+    #[test]
+    fn create_broadcast_vec_from_scalar() {
+        let vec2: Vec2<f32> = 2.5.into();
+        assert_eq!(vec2.x, 2.5);
+        assert_eq!(vec2.y, 2.5);
+    }
+
+    #[test]
+    fn create_full_vec() {
+        let vec2: Vec2<f32> = Vec2::from((1, 2));
+        assert_eq!(vec2.x, 1.0);
+        assert_eq!(vec2.y, 2.0);
+    }
+
+    #[test]
+    fn length_34_triangle() {
+        let vec: Vec2<f32> = (3.0, 4.0).into();
+        assert_eq!(vec.length(), 5.0);
+        assert_eq!(vec.length_squared(), 25.0);
+    }
+
+    #[test]
+    fn dot_known_values() {
+        let a: Vec2<f32> = (1.0, 2.0).into();
+        let b: Vec2<f32> = (3.0, 4.0).into();
+        assert_eq!(a.dot(&b), 11.0);
+    }
+
+    #[test]
+    fn dot_perpendicular() {
+        let a: Vec2<f64> = (1.0, 0.0).into();
+        let b: Vec2<f64> = (0.0, 3.0).into();
+        assert_eq!(a.dot(&b), 0.0);
+    }
+
+    #[test]
+    fn normalize_preserves_direction() {
+        let vec: Vec2<f32> = (3.0, 4.0).into();
+        let normalized = vec.normalize();
+        assert_eq!(normalized.x, 0.6);
+        assert_eq!(normalized.y, 0.8);
+    }
+
+    #[test]
+    fn try_normalize_zero_vector_is_none() {
+        let zero: Vec2<f32> = Vec2::new();
+        assert!(zero.try_normalize().is_none());
+    }
+
+    // ============ Arithmetic Tests ============
+
+    #[test]
+    fn add_two_vectors() {
+        let a: Vec2<f32> = (1.0, 2.0).into();
+        let b: Vec2<f32> = (3.0, 4.0).into();
+        let result = &a + &b;
+        assert_eq!(result.x, 4.0);
+        assert_eq!(result.y, 6.0);
+    }
+
+    #[test]
+    fn sub_two_vectors() {
+        let a: Vec2<f64> = (5.0, 7.0).into();
+        let b: Vec2<f64> = (1.0, 2.0).into();
+        let result = a - b;
+        assert_eq!(result.x, 4.0);
+        assert_eq!(result.y, 5.0);
+    }
+
+    #[test]
+    fn mul_component_wise() {
+        let a: Vec2<f32> = (2.0, 3.0).into();
+        let b: Vec2<f32> = (4.0, 5.0).into();
+        let result = a * b;
+        assert_eq!(result.x, 8.0);
+        assert_eq!(result.y, 15.0);
+    }
+
+    #[test]
+    fn mul_vector_by_scalar() {
+        let a: Vec2<f32> = (1.0, 2.0).into();
+        let result = &a * 2.0;
+        assert_eq!(result.x, 2.0);
+        assert_eq!(result.y, 4.0);
+    }
+
+    #[test]
+    fn div_vector_by_scalar() {
+        let a: Vec2<f64> = (2.0, 4.0).into();
+        let result = a / 2.0;
+        assert_eq!(result.x, 1.0);
+        assert_eq!(result.y, 2.0);
+    }
+
+    #[test]
+    fn map_and_zip_with() {
+        let a: Vec2<f32> = (1.0, -2.0).into();
+        let b: Vec2<f32> = (0.0, 3.0).into();
+        assert_eq!(a.map(f32::abs), Vec2::from((1.0, 2.0)));
+        assert_eq!(a.zip_with(b, f32::max), Vec2::from((1.0, 3.0)));
+    }
+}
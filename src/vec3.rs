@@ -1,38 +1,256 @@
-use num_traits::{Float, NumCast, ToPrimitive};
-use std::ops::{Add, Mul, Sub};
+use crate::vec2::Vec2;
+use num_traits::{Float, NumCast, SaturatingAdd, ToPrimitive, WrappingAdd, WrappingSub};
+use std::fmt;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
 
-pub struct Vec3<T: Float> {
+/// A three-component vector.
+///
+/// The struct is `repr(C)`: `x`, `y`, and `z` are laid out contiguously in that
+/// order with no padding, exactly like `[T; 3]`. A `&[Vec3<f32>]` can therefore
+/// be handed to C code or uploaded to a GPU buffer as tightly packed floats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Vec3<T> {
     pub x: T,
     pub y: T,
 
     pub z: T,
 }
 
-impl<T: Float + Default> Vec3<T> {
-    pub fn new() -> Self {
-        Vec3 {
-            x: T::default(),
-            y: T::default(),
-            z: T::default(),
+impl<T: Float> Vec3<T> {
+    /// Average of `points`, or the zero vector for an empty slice.
+    pub fn centroid(points: &[Vec3<T>]) -> Vec3<T> {
+        if points.is_empty() {
+            return Vec3::splat(0);
+        }
+        let count = T::from(points.len()).unwrap();
+        points.iter().sum::<Vec3<T>>() / count
+    }
+
+    /// Barycentric weights `(u, v, w)` of `p` with respect to triangle `abc`,
+    /// so that `p = a * u + b * v + c * w` and `u + v + w = 1`. A point off
+    /// the triangle's plane is projected onto it first.
+    ///
+    /// Solves the 2x2 normal equations by Cramer's rule (Ericson, *Real-Time
+    /// Collision Detection*, 3.4). A degenerate triangle (collinear or
+    /// coincident vertices) has no unique answer and returns `(0, 0, 0)`,
+    /// which callers can detect because the weights don't sum to 1.
+    pub fn barycentric(p: Vec3<T>, a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> (T, T, T) {
+        let (ab, ac, ap) = (b - a, c - a, p - a);
+        let d00 = ab.dot(&ab);
+        let d01 = ab.dot(&ac);
+        let d11 = ac.dot(&ac);
+        let d20 = ap.dot(&ab);
+        let d21 = ap.dot(&ac);
+        // `denom` is |ab x ac|^2, so compare against the edge lengths to
+        // catch slivers regardless of the triangle's scale.
+        let denom = d00 * d11 - d01 * d01;
+        if denom <= T::epsilon() * d00 * d11 {
+            return (T::zero(), T::zero(), T::zero());
+        }
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        (T::one() - v - w, v, w)
+    }
+
+    pub fn distance(&self, other: &Vec3<T>) -> T {
+        (self - other).length()
+    }
+
+    /// Squared distance, avoiding the square root when only comparing distances.
+    pub fn distance_squared(&self, other: &Vec3<T>) -> T {
+        (self - other).length_squared()
+    }
+
+    /// Angle in radians between `self` and `other`, in `0..=PI`. The cosine
+    /// is clamped to `-1..=1` so rounding on nearly parallel vectors can't
+    /// produce NaN. Returns 0 if either vector has zero length.
+    pub fn angle_between(&self, other: &Vec3<T>) -> T {
+        let len_product = self.length() * other.length();
+        if len_product == T::zero() {
+            return T::zero();
         }
+        let cos = self.dot(other) / len_product;
+        cos.max(-T::one()).min(T::one()).acos()
     }
 
-    fn splat<U: ToPrimitive + Copy>(val: U) -> Self {
+    // Spherical coordinates use `theta` as the polar angle from +Y and `phi`
+    // as the azimuth in the XZ plane, measured from +X towards +Z:
+    // `(r sin(theta) cos(phi), r cos(theta), r sin(theta) sin(phi))`.
+
+    pub fn from_spherical(radius: T, theta: T, phi: T) -> Vec3<T> {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
         Vec3 {
-            x: NumCast::from(val).unwrap(),
-            y: NumCast::from(val).unwrap(),
-            z: NumCast::from(val).unwrap(),
+            x: radius * sin_theta * cos_phi,
+            y: radius * cos_theta,
+            z: radius * sin_theta * sin_phi,
+        }
+    }
+
+    /// Returns `(radius, theta, phi)` with `theta` in `[0, pi]` and `phi` in
+    /// `(-pi, pi]`. On the Y axis `phi` is undefined and reported as 0; the
+    /// zero vector gives all zeros.
+    pub fn to_spherical(&self) -> (T, T, T) {
+        let radius = self.length();
+        if radius == T::zero() {
+            return (T::zero(), T::zero(), T::zero());
+        }
+        let cos_theta = (self.y / radius).max(-T::one()).min(T::one());
+        let phi = if self.x == T::zero() && self.z == T::zero() {
+            T::zero()
+        } else {
+            // Adding zero turns -0.0 into +0.0, so the negative X axis gives
+            // pi rather than -pi and `phi` stays in `(-pi, pi]`.
+            (self.z + T::zero()).atan2(self.x)
+        };
+        (radius, cos_theta.acos(), phi)
+    }
+
+    /// Component of `self` parallel to `other`. Projecting onto a zero vector
+    /// returns a zero vector.
+    pub fn project_onto(&self, other: &Vec3<T>) -> Vec3<T> {
+        let len_sq = other.length_squared();
+        if len_sq == T::zero() {
+            return other * T::zero();
+        }
+        other * (self.dot(other) / len_sq)
+    }
+
+    /// Component of `self` perpendicular to `other`, so that
+    /// `project_onto(other) + reject_from(other) == self`.
+    pub fn reject_from(&self, other: &Vec3<T>) -> Vec3<T> {
+        self - &self.project_onto(other)
+    }
+
+    /// Returns `(tangent, bitangent, normal)`: mutually orthogonal unit
+    /// vectors forming a right-handed frame, with `normal = self.normalize()`.
+    ///
+    /// Uses the branchless construction from Duff et al., "Building an
+    /// Orthonormal Basis, Revisited" (2017), which stays stable on every axis
+    /// including `-Z`. A zero vector yields `(X, Y, zero)`.
+    pub fn orthonormal_basis(&self) -> (Vec3<T>, Vec3<T>, Vec3<T>) {
+        let one = T::one();
+        let n = self.normalize();
+        let sign = one.copysign(n.z);
+        let a = -one / (sign + n.z);
+        let b = n.x * n.y * a;
+        let tangent = Vec3 {
+            x: one + sign * n.x * n.x * a,
+            y: sign * b,
+            z: -sign * n.x,
+        };
+        let bitangent = Vec3 {
+            x: b,
+            y: sign + n.y * n.y * a,
+            z: -n.y,
+        };
+        (tangent, bitangent, n)
+    }
+
+    // The min/max/clamp family works per component and follows `f32::min` and
+    // `f32::max` for NaN: a NaN component loses to the other operand, so a
+    // NaN in `self` is clamped to `lo` rather than propagating.
+
+    pub fn min(self, other: Vec3<T>) -> Vec3<T> {
+        self.zip_with(other, T::min)
+    }
+
+    pub fn max(self, other: Vec3<T>) -> Vec3<T> {
+        self.zip_with(other, T::max)
+    }
+
+    /// Clamps each component into `lo..=hi`. Unlike `f32::clamp` this does not
+    /// panic when `lo > hi`; `hi` wins.
+    pub fn clamp(self, lo: Vec3<T>, hi: Vec3<T>) -> Vec3<T> {
+        self.max(lo).min(hi)
+    }
+
+    pub fn clamp_scalar(self, lo: T, hi: T) -> Vec3<T> {
+        self.map(|c| c.max(lo).min(hi))
+    }
+
+    /// Mirrors `self` about the plane with the given `normal`, which must be
+    /// normalized.
+    pub fn reflect(&self, normal: &Vec3<T>) -> Vec3<T> {
+        let two = T::one() + T::one();
+        self - &(normal * (two * self.dot(normal)))
+    }
+
+    /// Bends `self` through a surface using Snell's law, where `eta` is the
+    /// ratio of refractive indices (incident over transmitted). Both `self`
+    /// and `normal` must be normalized, with `self` pointing into the surface
+    /// and `normal` pointing back against it. Returns `None` on total
+    /// internal reflection.
+    pub fn refract(&self, normal: &Vec3<T>, eta: T) -> Option<Vec3<T>> {
+        let cos_i = -self.dot(normal);
+        let k = T::one() - eta * eta * (T::one() - cos_i * cos_i);
+        if k < T::zero() {
+            return None;
         }
+        Some(self * eta + normal * (eta * cos_i - k.sqrt()))
+    }
+
+    /// True when every component is finite (neither infinite nor NaN).
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// True when any component is NaN.
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// True when every component differs from `other`'s by at most
+    /// `epsilon`.
+    pub fn abs_diff_eq(&self, other: &Vec3<T>, epsilon: T) -> bool {
+        (self - other).iter().all(|d| d.abs() <= epsilon)
+    }
+
+    /// True when every component is within `epsilon` of `other`'s, or within
+    /// `max_relative` times the larger of the two magnitudes. The absolute
+    /// check keeps values near zero from failing a purely relative test.
+    pub fn relative_eq(&self, other: &Vec3<T>, epsilon: T, max_relative: T) -> bool {
+        self.iter().zip(other.iter()).all(|(&a, &b)| {
+            let diff = (a - b).abs();
+            diff <= epsilon || diff <= a.abs().max(b.abs()) * max_relative
+        })
+    }
+
+    /// Linear blend from `self` (at `t = 0`) to `other` (at `t = 1`).
+    pub fn lerp(&self, other: &Vec3<T>, t: T) -> Vec3<T> {
+        self * (T::one() - t) + other * t
     }
 
-    pub fn length(&self) -> T {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    // GLSL-style per-component `step` and `smoothstep`, with the same argument
+    // order as the shader built-ins so snippets port directly.
+
+    /// 0 where `x < edge`, 1 otherwise.
+    pub fn step(edge: Vec3<T>, x: Vec3<T>) -> Vec3<T> {
+        edge.zip_with(x, |e, x| if x < e { T::zero() } else { T::one() })
     }
 
-    pub fn dot(&self, other: &Vec3<T>) -> T {
-        self.x * other.x + self.y * other.y + self.z * other.z
+    /// Hermite interpolation `t * t * (3 - 2t)` with
+    /// `t = clamp((x - edge0) / (edge1 - edge0), 0, 1)`. GLSL leaves
+    /// `edge0 == edge1` undefined; here that component falls back to `step`.
+    pub fn smoothstep(edge0: Vec3<T>, edge1: Vec3<T>, x: Vec3<T>) -> Vec3<T> {
+        let (two, three) = (T::one() + T::one(), T::one() + T::one() + T::one());
+        let hermite = |e0: T, e1: T, x: T| {
+            if e0 == e1 {
+                return if x < e0 { T::zero() } else { T::one() };
+            }
+            let t = ((x - e0) / (e1 - e0)).max(T::zero()).min(T::one());
+            t * t * (three - two * t)
+        };
+        Vec3 {
+            x: hermite(edge0.x, edge1.x, x.x),
+            y: hermite(edge0.y, edge1.y, x.y),
+            z: hermite(edge0.z, edge1.z, x.z),
+        }
     }
+}
 
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Vec3<T> {
     pub fn cross(&self, other: &Vec3<T>) -> Vec3<T> {
         Vec3 {
             x: self.y * other.z - self.z * other.y,
@@ -41,58 +259,292 @@ impl<T: Float + Default> Vec3<T> {
         }
     }
 
-    pub fn normalize(&self) -> Vec3<T> {
-        let len_sq = self.dot(self);
-        if len_sq > T::zero() {
-            let inv = T::one() / len_sq.sqrt();
-            Vec3 {
-                x: self.x * inv,
-                y: self.y * inv,
-                z: self.z * inv,
-            }
-        } else {
-            Vec3 {
-                x: self.x,
-                y: self.y,
-                z: self.z,
-            }
-        }
+    /// `a . (b x c)`: the signed volume of the parallelepiped spanned by the
+    /// three vectors. Positive when `a, b, c` form a right-handed set, zero
+    /// when they are coplanar.
+    pub fn scalar_triple(a: &Vec3<T>, b: &Vec3<T>, c: &Vec3<T>) -> T {
+        let bc = b.cross(c);
+        a.x * bc.x + a.y * bc.y + a.z * bc.z
+    }
+
+    /// `a x (b x c)`, which equals `b (a . c) - c (a . b)` and lies in the
+    /// plane of `b` and `c`.
+    pub fn vector_triple(a: &Vec3<T>, b: &Vec3<T>, c: &Vec3<T>) -> Vec3<T> {
+        a.cross(&b.cross(c))
     }
 }
 
-impl<T: Float + Default> Add<&Vec3<T>> for &Vec3<T> {
-    type Output = Vec3<T>;
-    fn add(self, other: &Vec3<T>) -> Vec3<T> {
+// Overflow-safe arithmetic for integer vectors, applying the matching integer
+// operation to each component.
+
+impl<T: SaturatingAdd> Vec3<T> {
+    pub fn saturating_add(&self, other: &Vec3<T>) -> Vec3<T> {
         Vec3 {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
+            x: self.x.saturating_add(&other.x),
+            y: self.y.saturating_add(&other.y),
+            z: self.z.saturating_add(&other.z),
         }
     }
 }
 
-impl<T: Float + Default> Sub<&Vec3<T>> for &Vec3<T> {
-    type Output = Vec3<T>;
-    fn sub(self, other: &Vec3<T>) -> Vec3<T> {
+impl<T: WrappingAdd + WrappingSub> Vec3<T> {
+    pub fn wrapping_add(&self, other: &Vec3<T>) -> Vec3<T> {
+        Vec3 {
+            x: self.x.wrapping_add(&other.x),
+            y: self.y.wrapping_add(&other.y),
+            z: self.z.wrapping_add(&other.z),
+        }
+    }
+
+    pub fn wrapping_sub(&self, other: &Vec3<T>) -> Vec3<T> {
         Vec3 {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
+            x: self.x.wrapping_sub(&other.x),
+            y: self.y.wrapping_sub(&other.y),
+            z: self.z.wrapping_sub(&other.z),
         }
     }
 }
 
-impl<T: Float + Default> Mul<T> for &Vec3<T> {
-    type Output = Vec3<T>;
-    fn mul(self, other: T) -> Vec3<T> {
+// Named spellings of the component-wise `*` and `/` operators, for code where
+// a bare `*` next to `dot` and `cross` could be misread.
+impl<T: Copy + Mul<Output = T> + Div<Output = T>> Vec3<T> {
+    /// The Hadamard product: `(x1 * x2, y1 * y2, z1 * z2)`.
+    pub fn component_mul(&self, other: &Vec3<T>) -> Vec3<T> {
+        Vec3 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+
+    pub fn component_div(&self, other: &Vec3<T>) -> Vec3<T> {
         Vec3 {
-            x: self.x * other,
-            y: self.y * other,
-            z: self.z * other,
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+        }
+    }
+}
+
+impl<T> Vec3<T> {
+    /// Builds a vector from its components. Unlike `new` and the `From`
+    /// conversions this is a `const fn`, so it works in constants and statics.
+    pub const fn new_xyz(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub fn as_array(&self) -> &[T; 3] {
+        // SAFETY: `Vec3` is `repr(C)` with three fields of the same type, so it
+        // has the same size, alignment, and element order as `[T; 3]`.
+        unsafe { &*(self as *const Vec3<T> as *const [T; 3]) }
+    }
+
+    pub fn as_mut_array(&mut self) -> &mut [T; 3] {
+        // SAFETY: see `as_array`.
+        unsafe { &mut *(self as *mut Vec3<T> as *mut [T; 3]) }
+    }
+
+    /// Iterates over references to the components in x, y, z order.
+    pub fn iter(&self) -> std::array::IntoIter<&T, 3> {
+        [&self.x, &self.y, &self.z].into_iter()
+    }
+}
+
+macro_rules! impl_swizzles {
+    ($($name:ident -> $out:ident { $($dst:ident: $src:ident),+ })+) => {
+        impl<T: Copy> Vec3<T> {
+            $(
+                pub fn $name(&self) -> $out<T> {
+                    $out {
+                        $($dst: self.$src),+
+                    }
+                }
+            )+
+        }
+    };
+}
+
+// Shader-style swizzles. Rather than all 27 combinations, this covers every
+// reordering of the three components and the three in-order pairs.
+impl_swizzles! {
+    xzy -> Vec3 { x: x, y: z, z: y }
+    yxz -> Vec3 { x: y, y: x, z: z }
+    yzx -> Vec3 { x: y, y: z, z: x }
+    zxy -> Vec3 { x: z, y: x, z: y }
+    zyx -> Vec3 { x: z, y: y, z: x }
+    xy -> Vec2 { x: x, y: y }
+    xz -> Vec2 { x: x, y: z }
+    yz -> Vec2 { x: y, y: z }
+}
+
+macro_rules! impl_vec3_consts {
+    ($($t:ty),+) => {
+        $(
+            impl Vec3<$t> {
+                pub const ZERO: Self = Vec3 { x: 0 as $t, y: 0 as $t, z: 0 as $t };
+                pub const ONE: Self = Vec3 { x: 1 as $t, y: 1 as $t, z: 1 as $t };
+                pub const X: Self = Vec3 { x: 1 as $t, y: 0 as $t, z: 0 as $t };
+                pub const Y: Self = Vec3 { x: 0 as $t, y: 1 as $t, z: 0 as $t };
+                pub const Z: Self = Vec3 { x: 0 as $t, y: 0 as $t, z: 1 as $t };
+            }
+        )+
+    };
+}
+
+impl_vec3_consts!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// Color helpers for `Vec3<f32>` holding RGB, using the piecewise sRGB transfer
+// function from IEC 61966-2-1 rather than a plain 2.2 gamma.
+impl Vec3<f32> {
+    /// Decodes sRGB-encoded components to linear light.
+    pub fn to_linear(&self) -> Vec3<f32> {
+        self.map(|c| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    }
+
+    /// Encodes linear components with the sRGB transfer function.
+    pub fn to_srgb(&self) -> Vec3<f32> {
+        self.map(|c| {
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        })
+    }
+
+    /// Relative luminance using the Rec. 709 weights. Expects linear RGB.
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.x + 0.7152 * self.y + 0.0722 * self.z
+    }
+}
+
+impl_vec_common!(Vec3 { x, y, z });
+impl_vec_ops!(Vec3 { x, y, z });
+impl_vec_from_primitives!(Vec3 { x, y, z });
+
+/// Formats as `(x, y, z)`. Width, precision, and sign flags are applied to
+/// each component, so `{:.2}` prints every component with two decimals.
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        self.x.fmt(f)?;
+        f.write_str(", ")?;
+        self.y.fmt(f)?;
+        f.write_str(", ")?;
+        self.z.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+impl<T> IntoIterator for Vec3<T> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y, self.z].into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec3<T> {
+    type Item = &'a T;
+    type IntoIter = std::array::IntoIter<&'a T, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Index<usize> for Vec3<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vec3 index out of range: {index} (expected 0, 1, or 2)"),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vec3 index out of range: {index} (expected 0, 1, or 2)"),
+        }
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3] {
+    fn from(v: Vec3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+/// Why a slice or `Vec` couldn't be converted to a `Vec3`. Both variants
+/// carry the length that was actually supplied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Vec3ConvertError {
+    TooFew(usize),
+    TooMany(usize),
+}
+
+impl Vec3ConvertError {
+    // Only called once the length is known not to be 3.
+    fn from_len(len: usize) -> Vec3ConvertError {
+        if len < 3 {
+            Vec3ConvertError::TooFew(len)
+        } else {
+            Vec3ConvertError::TooMany(len)
+        }
+    }
+}
+
+impl fmt::Display for Vec3ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vec3ConvertError::TooFew(len) => {
+                write!(f, "too few components for Vec3: expected 3, got {len}")
+            }
+            Vec3ConvertError::TooMany(len) => {
+                write!(f, "too many components for Vec3: expected 3, got {len}")
+            }
         }
     }
 }
 
+impl std::error::Error for Vec3ConvertError {}
+
+impl<T: Copy> TryFrom<&[T]> for Vec3<T> {
+    type Error = Vec3ConvertError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        <[T; 3]>::try_from(slice)
+            .map(Vec3::from)
+            .map_err(|_| Vec3ConvertError::from_len(slice.len()))
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for Vec3<T> {
+    type Error = Vec3ConvertError;
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        <[T; 3]>::try_from(vec)
+            .map(Vec3::from)
+            .map_err(|vec| Vec3ConvertError::from_len(vec.len()))
+    }
+}
+
 impl<T: Float, U: ToPrimitive> From<(U, U, U)> for Vec3<T> {
     fn from((x, y, z): (U, U, U)) -> Self {
         Vec3 {
@@ -104,6 +556,9 @@ impl<T: Float, U: ToPrimitive> From<(U, U, U)> for Vec3<T> {
 }
 
 #[cfg(test)]
+// The reference operator impls are exercised on purpose even though the
+// vectors are `Copy`.
+#[allow(clippy::op_ref)]
 mod tests {
     use super::*;
 
@@ -123,6 +578,19 @@ mod tests {
         assert_eq!(vec3.z, 11.0);
     }
 
+    #[test]
+    fn create_broadcast_vec_from_scalar() {
+        let vec3: Vec3<f32> = 2.5.into();
+        assert_eq!(vec3.x, 2.5);
+        assert_eq!(vec3.y, 2.5);
+        assert_eq!(vec3.z, 2.5);
+
+        let vec3: Vec3<u8> = Vec3::from(7);
+        assert_eq!(vec3.x, 7);
+        assert_eq!(vec3.y, 7);
+        assert_eq!(vec3.z, 7);
+    }
+
     #[test]
     fn create_full_vec() {
         let vec3: Vec3<f32> = Vec3::from((1, 2, 3));
@@ -168,6 +636,23 @@ mod tests {
         assert_eq!(vec.length(), 7.0);
     }
 
+    #[test]
+    fn dot_orthogonal_unit_vectors() {
+        let unit_x: Vec3<f64> = (1.0, 0.0, 0.0).into();
+        let unit_y: Vec3<f64> = (0.0, 1.0, 0.0).into();
+        let unit_z: Vec3<f64> = (0.0, 0.0, 1.0).into();
+        assert_eq!(unit_x.dot(&unit_y), 0.0);
+        assert_eq!(unit_y.dot(&unit_z), 0.0);
+        assert_eq!(unit_z.dot(&unit_x), 0.0);
+    }
+
+    #[test]
+    fn dot_integer_vectors() {
+        let a = Vec3 { x: 1, y: 2, z: 3 };
+        let b = Vec3 { x: 4, y: 5, z: 6 };
+        assert_eq!(a.dot(&b), 32);
+    }
+
     #[test]
     fn dot_parallel_vectors() {
         let a: Vec3<f32> = (1.0, 0.0, 0.0).into();
@@ -217,6 +702,36 @@ mod tests {
         assert_eq!(normalized.length(), 1.0);
     }
 
+    #[test]
+    fn length_squared_345_triangle() {
+        let vec: Vec3<f32> = (3.0, 4.0, 0.0).into();
+        assert_eq!(vec.length_squared(), 25.0);
+    }
+
+    #[test]
+    fn normalize_zero_vector_stays_zero() {
+        let zero: Vec3<f32> = Vec3::new();
+        let normalized = zero.normalize();
+        assert_eq!(normalized.x, 0.0);
+        assert_eq!(normalized.y, 0.0);
+        assert_eq!(normalized.z, 0.0);
+    }
+
+    #[test]
+    fn try_normalize_zero_vector_is_none() {
+        let zero: Vec3<f64> = Vec3::new();
+        assert!(zero.try_normalize().is_none());
+    }
+
+    #[test]
+    fn try_normalize_345_triangle() {
+        let vec: Vec3<f64> = (3.0, 4.0, 0.0).into();
+        let normalized = vec.try_normalize().unwrap();
+        assert_eq!(normalized.x, 0.6);
+        assert_eq!(normalized.y, 0.8);
+        assert_eq!(normalized.z, 0.0);
+    }
+
     #[test]
     fn normalize_arbitrary_vector() {
         let vec: Vec3<f32> = (2.0, 3.0, 6.0).into();
@@ -256,6 +771,16 @@ mod tests {
         assert_eq!(result.z, 0.0);
     }
 
+    #[test]
+    fn cross_product_integer_vectors() {
+        let x_axis = Vec3 { x: 1, y: 0, z: 0 };
+        let y_axis = Vec3 { x: 0, y: 1, z: 0 };
+        let result = x_axis.cross(&y_axis);
+        assert_eq!(result.x, 0);
+        assert_eq!(result.y, 0);
+        assert_eq!(result.z, 1);
+    }
+
     #[test]
     fn cross_product_anticommutative() {
         // a × b = -(b × a)
@@ -309,6 +834,42 @@ mod tests {
         assert!((result.dot(&b)).abs() < 1e-5);
     }
 
+    // ============ Triple Product Tests ============
+
+    #[test]
+    fn scalar_triple_of_axes_is_one() {
+        let (x, y, z) = (Vec3::<i32>::X, Vec3::<i32>::Y, Vec3::<i32>::Z);
+        assert_eq!(Vec3::scalar_triple(&x, &y, &z), 1);
+        assert_eq!(Vec3::scalar_triple(&y, &z, &x), 1);
+    }
+
+    #[test]
+    fn scalar_triple_swap_flips_sign() {
+        let a = Vec3 { x: 1, y: 2, z: 3 };
+        let b = Vec3 { x: -2, y: 0, z: 5 };
+        let c = Vec3 { x: 4, y: 1, z: -1 };
+        let volume = Vec3::scalar_triple(&a, &b, &c);
+        assert_ne!(volume, 0);
+        assert_eq!(Vec3::scalar_triple(&b, &a, &c), -volume);
+        assert_eq!(Vec3::scalar_triple(&a, &c, &b), -volume);
+    }
+
+    #[test]
+    fn scalar_triple_coplanar_is_zero() {
+        let a: Vec3<f64> = (1.0, 0.0, 0.0).into();
+        let b: Vec3<f64> = (0.0, 1.0, 0.0).into();
+        assert_eq!(Vec3::scalar_triple(&a, &b, &(a * 2.0 + b)), 0.0);
+    }
+
+    #[test]
+    fn vector_triple_expansion() {
+        let a = Vec3 { x: 1, y: 2, z: 3 };
+        let b = Vec3 { x: -2, y: 0, z: 5 };
+        let c = Vec3 { x: 4, y: 1, z: -1 };
+        let expected = b * a.dot(&c) - c * a.dot(&b);
+        assert_eq!(Vec3::vector_triple(&a, &b, &c), expected);
+    }
+
     // ============ Addition Tests ============
 
     #[test]
@@ -419,4 +980,982 @@ mod tests {
         assert_eq!(result.y, 2.0);
         assert_eq!(result.z, 3.0);
     }
+
+    #[test]
+    fn mul_owned_vector_by_scalar_f64() {
+        let a: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        let result = a * 2.0;
+        assert_eq!(result.x, 2.0);
+        assert_eq!(result.y, 4.0);
+        assert_eq!(result.z, 6.0);
+    }
+
+    // ============ Scalar Division Tests ============
+
+    #[test]
+    fn div_vector_by_scalar() {
+        let a: Vec3<f32> = (2.0, 4.0, 6.0).into();
+        let result = &a / 2.0;
+        assert_eq!(result.x, 1.0);
+        assert_eq!(result.y, 2.0);
+        assert_eq!(result.z, 3.0);
+    }
+
+    #[test]
+    fn div_owned_vector_by_scalar_f64() {
+        let a: Vec3<f64> = (3.0, 6.0, 9.0).into();
+        let result = a / 3.0;
+        assert_eq!(result.x, 1.0);
+        assert_eq!(result.y, 2.0);
+        assert_eq!(result.z, 3.0);
+    }
+
+    // ============ Component-wise Tests ============
+
+    #[test]
+    fn add_owned_vectors_f64() {
+        let a: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        let b: Vec3<f64> = (4.0, 5.0, 6.0).into();
+        let result = a + b;
+        assert_eq!(result.x, 5.0);
+        assert_eq!(result.y, 7.0);
+        assert_eq!(result.z, 9.0);
+    }
+
+    #[test]
+    fn sub_owned_vectors_f64() {
+        let a: Vec3<f64> = (5.0, 7.0, 9.0).into();
+        let b: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        let result = a - b;
+        assert_eq!(result.x, 4.0);
+        assert_eq!(result.y, 5.0);
+        assert_eq!(result.z, 6.0);
+    }
+
+    #[test]
+    fn mul_component_wise() {
+        let a: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let b: Vec3<f32> = (4.0, 5.0, 6.0).into();
+        let result = &a * &b;
+        assert_eq!(result.x, 4.0);
+        assert_eq!(result.y, 10.0);
+        assert_eq!(result.z, 18.0);
+    }
+
+    #[test]
+    fn div_component_wise() {
+        let a: Vec3<f64> = (4.0, 10.0, 18.0).into();
+        let b: Vec3<f64> = (4.0, 5.0, 6.0).into();
+        let result = a / b;
+        assert_eq!(result.x, 1.0);
+        assert_eq!(result.y, 2.0);
+        assert_eq!(result.z, 3.0);
+    }
+
+    #[test]
+    fn component_mul_named_method() {
+        let a = Vec3 { x: 2, y: 3, z: 4 };
+        let b = Vec3 { x: 5, y: 6, z: 7 };
+        assert_eq!(
+            a.component_mul(&b),
+            Vec3 {
+                x: 10,
+                y: 18,
+                z: 28
+            }
+        );
+        assert_eq!(a.component_mul(&b), a * b);
+    }
+
+    #[test]
+    fn component_div_named_method() {
+        let a: Vec3<f32> = (10.0, 18.0, 28.0).into();
+        let b: Vec3<f32> = (5.0, 6.0, 7.0).into();
+        assert_eq!(a.component_div(&b), Vec3::from((2.0, 3.0, 4.0)));
+        assert_eq!(a.component_div(&b), a / b);
+    }
+
+    // ============ Integer Overflow Tests ============
+
+    #[test]
+    fn saturating_add_clamps_to_max() {
+        let v = Vec3::<u8>::from(250).saturating_add(&Vec3::from(10));
+        assert_eq!(v, Vec3::<u8>::from(255));
+        let mixed = Vec3 {
+            x: 1u8,
+            y: 200,
+            z: 255,
+        }
+        .saturating_add(&Vec3::from(100));
+        assert_eq!(
+            mixed,
+            Vec3 {
+                x: 101,
+                y: 255,
+                z: 255
+            }
+        );
+    }
+
+    #[test]
+    fn saturating_add_clamps_signed_both_ways() {
+        let a = Vec3 {
+            x: i32::MAX,
+            y: i32::MIN,
+            z: 0,
+        };
+        let b = Vec3 { x: 1, y: -1, z: -5 };
+        assert_eq!(
+            a.saturating_add(&b),
+            Vec3 {
+                x: i32::MAX,
+                y: i32::MIN,
+                z: -5
+            }
+        );
+    }
+
+    #[test]
+    fn wrapping_add_and_sub() {
+        let a = Vec3 {
+            x: 250u8,
+            y: 0,
+            z: 7,
+        };
+        assert_eq!(a.wrapping_add(&Vec3::from(10)), Vec3 { x: 4, y: 10, z: 17 });
+        assert_eq!(
+            a.wrapping_sub(&Vec3::from(10)),
+            Vec3 {
+                x: 240,
+                y: 246,
+                z: 253
+            }
+        );
+        let min = Vec3::<i32>::from(i32::MIN);
+        assert_eq!(min.wrapping_sub(&Vec3::from(1)), Vec3::from(i32::MAX));
+    }
+
+    // ============ Indexing Tests ============
+
+    #[test]
+    fn index_each_component() {
+        let a: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        assert_eq!(a[0], 1.0);
+        assert_eq!(a[1], 2.0);
+        assert_eq!(a[2], 3.0);
+    }
+
+    #[test]
+    fn index_mut_each_component() {
+        let mut a: Vec3<f32> = Vec3::new();
+        for i in 0..3 {
+            a[i] = i as f32 + 1.0;
+        }
+        assert_eq!(a.x, 1.0);
+        assert_eq!(a.y, 2.0);
+        assert_eq!(a.z, 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3 index out of range: 3")]
+    fn index_out_of_range_panics() {
+        let a: Vec3<f32> = Vec3::new();
+        let _ = a[3];
+    }
+
+    // ============ Derived Trait Tests ============
+
+    #[test]
+    fn copy_and_compare_float_vectors() {
+        let a: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let b = a;
+        assert_eq!(a, b);
+        assert_ne!(a, Vec3::from((1.0, 2.0, 4.0)));
+    }
+
+    #[test]
+    fn debug_format() {
+        let a: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        assert_eq!(format!("{:?}", a), "Vec3 { x: 1.0, y: 2.0, z: 3.0 }");
+    }
+
+    #[test]
+    fn integer_vectors_are_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let a = Vec3 { x: 1, y: 2, z: 3 };
+        let b = a;
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&b));
+        assert_eq!(a, b);
+    }
+
+    // ============ Combinator Tests ============
+
+    #[test]
+    fn map_squares_each_component() {
+        let a: Vec3<f32> = (1.0, -2.0, 3.0).into();
+        let result = a.map(|c| c * c);
+        assert_eq!(result, Vec3::from((1.0, 4.0, 9.0)));
+    }
+
+    #[test]
+    fn map_changes_element_type() {
+        let a: Vec3<f64> = (1.5, 2.5, 3.5).into();
+        let result: Vec3<f32> = a.map(|c| c as f32);
+        assert_eq!(result, Vec3::from((1.5, 2.5, 3.5)));
+    }
+
+    #[test]
+    fn zip_with_component_max() {
+        let a: Vec3<f32> = (1.0, 5.0, 3.0).into();
+        let b: Vec3<f32> = (4.0, 2.0, 6.0).into();
+        let result = a.zip_with(b, f32::max);
+        assert_eq!(result, Vec3::from((4.0, 5.0, 6.0)));
+    }
+
+    // ============ Lerp Tests ============
+
+    #[test]
+    fn lerp_endpoints() {
+        let a: Vec3<f32> = (0.1, 0.2, 0.3).into();
+        let b: Vec3<f32> = (4.0, -5.0, 6.0).into();
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+    }
+
+    #[test]
+    fn lerp_midpoint() {
+        let a: Vec3<f64> = (0.0, 2.0, -4.0).into();
+        let b: Vec3<f64> = (2.0, 4.0, 4.0).into();
+        assert_eq!(a.lerp(&b, 0.5), Vec3::from((1.0, 3.0, 0.0)));
+    }
+
+    // ============ Step/Smoothstep Tests ============
+
+    #[test]
+    fn step_per_component() {
+        let edge: Vec3<f32> = Vec3::splat(0.5);
+        let x: Vec3<f32> = (0.0, 0.5, 1.0).into();
+        assert_eq!(Vec3::step(edge, x), Vec3::from((0.0f32, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn smoothstep_endpoints_and_midpoint() {
+        let e0: Vec3<f64> = Vec3::splat(1);
+        let e1: Vec3<f64> = Vec3::splat(3);
+        let x: Vec3<f64> = (-5.0, 2.0, 10.0).into();
+        assert_eq!(Vec3::smoothstep(e0, e1, x), Vec3::from((0.0, 0.5, 1.0)));
+        assert_eq!(Vec3::smoothstep(e0, e1, e0), Vec3::splat(0));
+        assert_eq!(Vec3::smoothstep(e0, e1, e1), Vec3::splat(1));
+    }
+
+    #[test]
+    fn smoothstep_eases_in_and_out() {
+        let (e0, e1): (Vec3<f64>, Vec3<f64>) = (Vec3::splat(0), Vec3::splat(1));
+        let s = Vec3::smoothstep(e0, e1, Vec3::from((0.25, 0.75, 0.1)));
+        assert_eq!(s.x, 0.15625);
+        assert_eq!(s.y, 0.84375);
+        assert!(s.z < 0.1);
+    }
+
+    #[test]
+    fn smoothstep_equal_edges_is_step() {
+        let edge: Vec3<f32> = Vec3::splat(2);
+        let x: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let s = Vec3::smoothstep(edge, edge, x);
+        assert!(!s.is_nan());
+        assert_eq!(s, Vec3::step(edge, x));
+    }
+
+    // ============ Compound Assignment Tests ============
+
+    #[test]
+    fn add_assign_accumulates() {
+        let mut sum: Vec3<f32> = Vec3::new();
+        let step: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        for _ in 0..3 {
+            sum += step;
+        }
+        assert_eq!(sum, Vec3::from((3.0, 6.0, 9.0)));
+    }
+
+    #[test]
+    fn sub_assign_vector() {
+        let mut a: Vec3<f32> = (5.0, 7.0, 9.0).into();
+        a -= Vec3::from((1.0, 2.0, 3.0));
+        assert_eq!(a, Vec3::from((4.0, 5.0, 6.0)));
+    }
+
+    #[test]
+    fn mul_assign_scalar_scales_all_components() {
+        let mut a: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        a *= 2.0;
+        assert_eq!(a, Vec3::from((2.0, 4.0, 6.0)));
+    }
+
+    #[test]
+    fn mul_assign_component_wise() {
+        let mut a: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        a *= Vec3::from((4.0, 5.0, 6.0));
+        assert_eq!(a, Vec3::from((4.0, 10.0, 18.0)));
+    }
+
+    #[test]
+    fn div_assign_scalar_and_vector() {
+        let mut a: Vec3<f32> = (4.0, 8.0, 12.0).into();
+        a /= 2.0;
+        assert_eq!(a, Vec3::from((2.0, 4.0, 6.0)));
+        a /= Vec3::from((2.0, 4.0, 3.0));
+        assert_eq!(a, Vec3::from((1.0, 1.0, 2.0)));
+    }
+
+    // ============ Negation Tests ============
+
+    #[test]
+    fn neg_float_vector() {
+        let a: Vec3<f32> = (1.0, -2.0, 3.0).into();
+        assert_eq!(-a, Vec3::from((-1.0, 2.0, -3.0)));
+        assert_eq!(-&a, Vec3::from((-1.0, 2.0, -3.0)));
+    }
+
+    #[test]
+    fn neg_signed_integer_vector() {
+        let a = Vec3 { x: 1, y: -2, z: 3 };
+        assert_eq!(-a, Vec3 { x: -1, y: 2, z: -3 });
+    }
+
+    // ============ Distance Tests ============
+
+    #[test]
+    fn distance_345_triangle() {
+        let origin: Vec3<f32> = Vec3::new();
+        let p: Vec3<f32> = (3.0, 4.0, 0.0).into();
+        assert_eq!(origin.distance(&p), 5.0);
+        assert_eq!(p.distance(&origin), 5.0);
+    }
+
+    #[test]
+    fn distance_squared_known_values() {
+        let a: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        let b: Vec3<f64> = (3.0, 5.0, 9.0).into();
+        assert_eq!(a.distance_squared(&b), 49.0);
+        assert_eq!(a.distance(&b), 7.0);
+    }
+
+    // ============ Min/Max/Clamp Tests ============
+
+    #[test]
+    fn min_max_component_wise() {
+        let a: Vec3<f32> = (1.0, 5.0, -3.0).into();
+        let b: Vec3<f32> = (4.0, 2.0, -6.0).into();
+        assert_eq!(a.min(b), Vec3::from((1.0, 2.0, -6.0)));
+        assert_eq!(a.max(b), Vec3::from((4.0, 5.0, -3.0)));
+    }
+
+    #[test]
+    fn clamp_exceeding_one_axis() {
+        let v: Vec3<f32> = (0.5, 2.0, 0.25).into();
+        let lo: Vec3<f32> = Vec3::new();
+        let hi: Vec3<f32> = Vec3::splat(1);
+        assert_eq!(v.clamp(lo, hi), Vec3::from((0.5, 1.0, 0.25)));
+    }
+
+    #[test]
+    fn clamp_scalar_bounds() {
+        let v: Vec3<f64> = (-1.0, 0.5, 3.0).into();
+        assert_eq!(v.clamp_scalar(0.0, 1.0), Vec3::from((0.0, 0.5, 1.0)));
+    }
+
+    #[test]
+    fn min_max_ignore_nan() {
+        let a: Vec3<f32> = (f32::NAN, 1.0, 1.0).into();
+        let b: Vec3<f32> = (2.0, 2.0, 2.0).into();
+        assert_eq!(a.min(b), Vec3::from((2.0, 1.0, 1.0)));
+        assert_eq!(a.max(b), Vec3::from((2.0, 2.0, 2.0)));
+        assert_eq!(a.clamp_scalar(0.0, 1.0).x, 0.0);
+    }
+
+    // ============ Iteration Tests ============
+
+    #[test]
+    fn into_iter_collects_in_order() {
+        let v = Vec3 { x: 1, y: 2, z: 3 };
+        let components: Vec<i32> = v.into_iter().collect();
+        assert_eq!(components, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn iter_by_reference() {
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let mut sum = 0.0;
+        for c in &v {
+            sum += c;
+        }
+        assert_eq!(sum, 6.0);
+        assert_eq!(v.iter().count(), 3);
+        assert_eq!(v.iter().copied().fold(f32::MIN, f32::max), 3.0);
+    }
+
+    // ============ Array Conversion Tests ============
+
+    #[test]
+    fn from_array() {
+        let v = Vec3::from([1.0f32, 2.0, 3.0]);
+        assert_eq!(v, Vec3::from((1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn into_array() {
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let arr: [f32; 3] = v.into();
+        assert_eq!(arr, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn as_array_views_components() {
+        let mut v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        assert_eq!(v.as_array(), &[1.0, 2.0, 3.0]);
+
+        v.as_mut_array()[1] = 5.0;
+        assert_eq!(v.y, 5.0);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let data = [1, 2, 3, 4];
+        assert_eq!(
+            Vec3::try_from(&data[..3]).unwrap(),
+            Vec3 { x: 1, y: 2, z: 3 }
+        );
+        assert_eq!(Vec3::try_from(&data[..2]), Err(Vec3ConvertError::TooFew(2)));
+        assert_eq!(Vec3::try_from(&data[..]), Err(Vec3ConvertError::TooMany(4)));
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let parsed: Vec<f32> = "1.5 -2 3".split(' ').map(|s| s.parse().unwrap()).collect();
+        assert_eq!(
+            Vec3::try_from(parsed).unwrap(),
+            Vec3::from((1.5, -2.0, 3.0))
+        );
+        assert_eq!(
+            Vec3::try_from(vec![String::from("x")]),
+            Err(Vec3ConvertError::TooFew(1))
+        );
+        assert_eq!(
+            Vec3::try_from(vec![1, 2, 3, 4, 5]),
+            Err(Vec3ConvertError::TooMany(5))
+        );
+        assert_eq!(
+            Vec3::<u8>::try_from(vec![]),
+            Err(Vec3ConvertError::TooFew(0))
+        );
+    }
+
+    #[test]
+    fn convert_error_messages() {
+        assert_eq!(
+            Vec3ConvertError::TooFew(2).to_string(),
+            "too few components for Vec3: expected 3, got 2"
+        );
+        assert_eq!(
+            Vec3ConvertError::TooMany(4).to_string(),
+            "too many components for Vec3: expected 3, got 4"
+        );
+    }
+
+    // ============ Layout Tests ============
+
+    #[test]
+    fn layout_matches_array() {
+        use std::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<Vec3<f32>>(), 12);
+        assert_eq!(align_of::<Vec3<f32>>(), align_of::<f32>());
+        assert_eq!(size_of::<Vec3<f64>>(), size_of::<[f64; 3]>());
+        assert_eq!(size_of::<[Vec3<f32>; 4]>(), 48);
+    }
+
+    // ============ Reflect/Refract Tests ============
+
+    #[test]
+    fn reflect_off_floor_bounces_up() {
+        let incoming: Vec3<f32> = (1.0, -1.0, 0.0).into();
+        let floor: Vec3<f32> = (0.0, 1.0, 0.0).into();
+        assert_eq!(incoming.reflect(&floor), Vec3::from((1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn refract_matching_indices_passes_straight_through() {
+        let incoming: Vec3<f32> = Vec3::from((1.0, -1.0, 0.0)).normalize();
+        let normal: Vec3<f32> = (0.0, 1.0, 0.0).into();
+        let refracted = incoming.refract(&normal, 1.0).unwrap();
+        assert!((refracted.x - incoming.x).abs() < 1e-6);
+        assert!((refracted.y - incoming.y).abs() < 1e-6);
+        assert!((refracted.z - incoming.z).abs() < 1e-6);
+    }
+
+    #[test]
+    fn refract_obeys_snells_law() {
+        // Air into glass: sin(theta_t) = sin(theta_i) / 1.5.
+        let sin_i: f64 = 0.6;
+        let incoming: Vec3<f64> = (sin_i, -(1.0 - sin_i * sin_i).sqrt(), 0.0).into();
+        let normal: Vec3<f64> = (0.0, 1.0, 0.0).into();
+        let refracted = incoming.refract(&normal, 1.0 / 1.5).unwrap();
+        assert!((refracted.x - sin_i / 1.5).abs() < 1e-12);
+        assert!((refracted.length() - 1.0).abs() < 1e-12);
+        assert!(refracted.y < 0.0);
+    }
+
+    #[test]
+    fn refract_total_internal_reflection_boundary() {
+        // Glass into air has a critical angle where sin(theta) = 1 / 1.5.
+        let normal: Vec3<f64> = (0.0, 1.0, 0.0).into();
+        let at_sin =
+            |sin_i: f64| -> Vec3<f64> { (sin_i, -(1.0 - sin_i * sin_i).sqrt(), 0.0).into() };
+
+        assert!(at_sin(0.66).refract(&normal, 1.5).is_some());
+        assert!(at_sin(0.67).refract(&normal, 1.5).is_none());
+    }
+
+    // ============ Constant Tests ============
+
+    #[test]
+    fn axis_constants() {
+        assert_eq!(Vec3::<f32>::X, Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(Vec3::<f32>::Y, Vec3::from((0.0, 1.0, 0.0)));
+        assert_eq!(Vec3::<f64>::Z, Vec3::from((0.0, 0.0, 1.0)));
+        assert_eq!(Vec3::<i32>::X, Vec3 { x: 1, y: 0, z: 0 });
+    }
+
+    #[test]
+    fn zero_and_one_constants() {
+        assert_eq!(Vec3::<f32>::ZERO, Vec3::new());
+        assert_eq!(Vec3::<f64>::ONE, Vec3::splat(1));
+        assert_eq!(Vec3::<u8>::ONE, Vec3::from(1));
+    }
+
+    #[test]
+    fn constants_usable_in_const_context() {
+        const DIRECTIONS: [Vec3<f32>; 3] = [Vec3::<f32>::X, Vec3::<f32>::Y, Vec3::<f32>::Z];
+        assert_eq!(DIRECTIONS[0].cross(&DIRECTIONS[1]), DIRECTIONS[2]);
+    }
+
+    // ============ Validation Tests ============
+
+    #[test]
+    fn finite_vector_passes_checks() {
+        let v: Vec3<f32> = (1.0, -2.0, 0.0).into();
+        assert!(v.is_finite());
+        assert!(!v.is_nan());
+    }
+
+    #[test]
+    fn infinity_is_not_finite_or_nan() {
+        let v: Vec3<f32> = (1.0, f32::INFINITY, 0.0).into();
+        assert!(!v.is_finite());
+        assert!(!v.is_nan());
+        assert!(!(Vec3::<f64>::X / 0.0).is_finite());
+    }
+
+    #[test]
+    fn nan_is_caught_by_both_checks() {
+        let v: Vec3<f64> = (0.0, 0.0, f64::NAN).into();
+        assert!(!v.is_finite());
+        assert!(v.is_nan());
+    }
+
+    // ============ Approximate Equality Tests ============
+
+    #[test]
+    fn abs_diff_eq_within_epsilon() {
+        let a: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        let b: Vec3<f64> = (1.0 + 1e-7, 2.0 - 1e-7, 3.0).into();
+        assert!(a.abs_diff_eq(&b, 1e-6));
+        assert!(!a.abs_diff_eq(&b, 1e-9));
+    }
+
+    #[test]
+    fn abs_diff_eq_after_normalize() {
+        let n: Vec3<f32> = Vec3::from((2.0, 3.0, 6.0)).normalize();
+        let expected: Vec3<f32> = (2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0).into();
+        assert!(n.abs_diff_eq(&expected, 1e-6));
+    }
+
+    #[test]
+    fn relative_eq_scales_with_magnitude() {
+        let a: Vec3<f64> = (1e6, 0.0, -1e6).into();
+        let b: Vec3<f64> = (1e6 + 0.5, 1e-12, -1e6).into();
+        assert!(!a.abs_diff_eq(&b, 1e-6));
+        assert!(a.relative_eq(&b, 1e-6, 1e-6));
+        assert!(!a.relative_eq(&b, 1e-6, 1e-9));
+    }
+
+    // ============ Angle Tests ============
+
+    #[test]
+    fn angle_between_axes_is_right_angle() {
+        let a: Vec3<f32> = (1.0, 0.0, 0.0).into();
+        let b: Vec3<f32> = (0.0, 2.0, 0.0).into();
+        assert!((a.angle_between(&b) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn angle_between_self_is_zero() {
+        let a: Vec3<f32> = (0.3, 0.7, -1.9).into();
+        assert_eq!(a.angle_between(&a), 0.0);
+    }
+
+    #[test]
+    fn angle_between_opposite_is_pi() {
+        let a: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        assert!((a.angle_between(&-a) - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn angle_between_zero_vector_is_zero() {
+        let a: Vec3<f32> = (1.0, 0.0, 0.0).into();
+        let zero: Vec3<f32> = Vec3::new();
+        assert_eq!(a.angle_between(&zero), 0.0);
+    }
+
+    // ============ Spherical Tests ============
+
+    #[test]
+    fn spherical_round_trip() {
+        let directions: [Vec3<f64>; 5] = [
+            (1.0, 2.0, 3.0).into(),
+            (-4.0, 0.5, -1.0).into(),
+            (0.0, -2.0, 5.0).into(),
+            (3.0, 0.0, -3.0).into(),
+            Vec3::from([-1.0, 0.0, -0.0]),
+        ];
+        for v in directions {
+            let (r, theta, phi) = v.to_spherical();
+            assert!(phi > -std::f64::consts::PI, "{v}: phi = {phi}");
+            assert!(Vec3::from_spherical(r, theta, phi).abs_diff_eq(&v, 1e-12));
+        }
+    }
+
+    #[test]
+    fn spherical_axes() {
+        use std::f64::consts::FRAC_PI_2;
+        let x: Vec3<f64> = Vec3::from_spherical(2.0, FRAC_PI_2, 0.0);
+        assert!(x.abs_diff_eq(&Vec3::from((2.0, 0.0, 0.0)), 1e-12));
+        let z: Vec3<f64> = Vec3::from_spherical(1.0, FRAC_PI_2, FRAC_PI_2);
+        assert!(z.abs_diff_eq(&Vec3::from((0.0, 0.0, 1.0)), 1e-12));
+    }
+
+    #[test]
+    fn from_spherical_zero_theta_is_pole() {
+        for phi in [0.0, 1.0, -2.5, 100.0] {
+            let v: Vec3<f32> = Vec3::from_spherical(1.0, 0.0, phi);
+            assert_eq!(v, Vec3::from((0.0f32, 1.0, 0.0)));
+        }
+    }
+
+    #[test]
+    fn to_spherical_poles_have_no_nan() {
+        let up: Vec3<f32> = (0.0, 3.0, 0.0).into();
+        assert_eq!(up.to_spherical(), (3.0, 0.0, 0.0));
+        let (r, theta, phi) = Vec3::<f32>::from((0.0, -2.0, 0.0)).to_spherical();
+        assert_eq!((r, phi), (2.0, 0.0));
+        assert!((theta - std::f32::consts::PI).abs() < 1e-6);
+        assert_eq!(Vec3::<f32>::ZERO.to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    // ============ Projection Tests ============
+
+    #[test]
+    fn project_onto_x_axis() {
+        let v: Vec3<f32> = (1.0, 1.0, 0.0).into();
+        let x_axis: Vec3<f32> = (3.0, 0.0, 0.0).into();
+        assert_eq!(v.project_onto(&x_axis), Vec3::from((1.0, 0.0, 0.0)));
+        assert_eq!(v.reject_from(&x_axis), Vec3::from((0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn project_and_reject_sum_to_self() {
+        let v: Vec3<f64> = (1.0, 2.0, 3.0).into();
+        let onto: Vec3<f64> = (-2.0, 0.5, 1.0).into();
+        let parallel = v.project_onto(&onto);
+        let perpendicular = v.reject_from(&onto);
+        assert!((parallel + perpendicular).abs_diff_eq(&v, 1e-12));
+        assert!(perpendicular.dot(&onto).abs() < 1e-12);
+    }
+
+    #[test]
+    fn project_onto_zero_vector_is_zero() {
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let zero: Vec3<f32> = Vec3::new();
+        assert_eq!(v.project_onto(&zero), zero);
+        assert_eq!(v.reject_from(&zero), v);
+    }
+
+    // ============ Orthonormal Basis Tests ============
+
+    fn assert_orthonormal(input: Vec3<f64>) {
+        let (t, b, n) = input.orthonormal_basis();
+        for v in [t, b, n] {
+            assert!((v.length() - 1.0).abs() < 1e-12, "{input}: {v} not unit");
+        }
+        for (p, q) in [(t, b), (b, n), (n, t)] {
+            assert!(p.dot(&q).abs() < 1e-12, "{input}: {p} . {q} != 0");
+        }
+        assert!(t.cross(&b).abs_diff_eq(&n, 1e-12), "{input}: left-handed");
+    }
+
+    #[test]
+    fn orthonormal_basis_for_axes() {
+        for axis in [
+            Vec3::<f64>::X,
+            Vec3::<f64>::Y,
+            Vec3::<f64>::Z,
+            -Vec3::<f64>::X,
+            -Vec3::<f64>::Y,
+            -Vec3::<f64>::Z,
+        ] {
+            assert_orthonormal(axis);
+        }
+    }
+
+    #[test]
+    fn orthonormal_basis_near_axes_and_arbitrary() {
+        assert_orthonormal((1e-9, -1e-9, -1.0).into());
+        assert_orthonormal((1e-7, 0.0, 1.0).into());
+        assert_orthonormal((1.0, 2.0, 3.0).into());
+        assert_orthonormal((-0.3, 0.9, -0.1).into());
+    }
+
+    #[test]
+    fn orthonormal_basis_keeps_normal_direction() {
+        let v: Vec3<f64> = (0.0, 0.0, 5.0).into();
+        let (_, _, n) = v.orthonormal_basis();
+        assert_eq!(n, Vec3::<f64>::Z);
+    }
+
+    // ============ Swizzle Tests ============
+
+    #[test]
+    fn swizzle_reverse() {
+        let v: Vec3<f32> = Vec3::from((1, 2, 3));
+        assert_eq!(v.zyx(), Vec3::from((3, 2, 1)));
+    }
+
+    #[test]
+    fn swizzle_permutations() {
+        let v = Vec3 { x: 1, y: 2, z: 3 };
+        assert_eq!(v.xzy(), Vec3 { x: 1, y: 3, z: 2 });
+        assert_eq!(v.yxz(), Vec3 { x: 2, y: 1, z: 3 });
+        assert_eq!(v.yzx(), Vec3 { x: 2, y: 3, z: 1 });
+        assert_eq!(v.zxy(), Vec3 { x: 3, y: 1, z: 2 });
+    }
+
+    #[test]
+    fn swizzle_pairs() {
+        let v: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        assert_eq!(v.xy(), Vec2::from((1.0, 2.0)));
+        assert_eq!(v.xz(), Vec2::from((1.0, 3.0)));
+        assert_eq!(v.yz(), Vec2::from((2.0, 3.0)));
+    }
+
+    // ============ Display Tests ============
+
+    #[test]
+    fn display_default() {
+        let v: Vec3<f32> = (1.0, -2.5, 3.0).into();
+        assert_eq!(format!("{}", v), "(1, -2.5, 3)");
+    }
+
+    #[test]
+    fn display_honors_precision() {
+        let v: Vec3<f32> = (1.5f32, 2.5, 3.5).into();
+        assert_eq!(format!("{:.1}", v), "(1.5, 2.5, 3.5)");
+        assert_eq!(format!("{:.2}", Vec3::<f64>::ONE), "(1.00, 1.00, 1.00)");
+    }
+
+    #[test]
+    fn display_honors_width_and_sign() {
+        let v = Vec3 { x: 1, y: -2, z: 30 };
+        assert_eq!(format!("{:>3}", v), "(  1,  -2,  30)");
+        assert_eq!(format!("{:+}", v), "(+1, -2, +30)");
+    }
+
+    // ============ Sum/Centroid Tests ============
+
+    #[test]
+    fn sum_unit_axes() {
+        let axes = [Vec3::<f32>::X, Vec3::<f32>::Y, Vec3::<f32>::Z];
+        assert_eq!(axes.iter().sum::<Vec3<f32>>(), Vec3::from((1.0, 1.0, 1.0)));
+        assert_eq!(axes.into_iter().sum::<Vec3<f32>>(), Vec3::<f32>::ONE);
+    }
+
+    #[test]
+    fn sum_empty_is_zero() {
+        let none: [Vec3<i32>; 0] = [];
+        assert_eq!(none.iter().sum::<Vec3<i32>>(), Vec3::new());
+    }
+
+    #[test]
+    fn centroid_symmetric_cloud_is_origin() {
+        let axes = [Vec3::<f32>::X, Vec3::<f32>::Y, Vec3::<f32>::Z];
+        let points: Vec<Vec3<f32>> = axes
+            .iter()
+            .flat_map(|&axis| [axis * 2.0, -axis * 2.0])
+            .collect();
+        assert_eq!(Vec3::centroid(&points), Vec3::new());
+    }
+
+    #[test]
+    fn centroid_of_triangle() {
+        let points: [Vec3<f64>; 3] = [
+            (0.0, 0.0, 0.0).into(),
+            (3.0, 0.0, 0.0).into(),
+            (0.0, 3.0, 3.0).into(),
+        ];
+        assert_eq!(Vec3::centroid(&points), Vec3::from((1.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn centroid_empty_is_zero() {
+        assert_eq!(Vec3::<f32>::centroid(&[]), Vec3::new());
+    }
+
+    // ============ Barycentric Tests ============
+
+    fn triangle() -> [Vec3<f64>; 3] {
+        [
+            (1.0, 0.0, 2.0).into(),
+            (4.0, 1.0, 0.0).into(),
+            (0.0, 5.0, 1.0).into(),
+        ]
+    }
+
+    fn assert_weights_near(got: (f64, f64, f64), want: (f64, f64, f64)) {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        assert!(
+            close(got.0, want.0) && close(got.1, want.1) && close(got.2, want.2),
+            "{got:?} != {want:?}"
+        );
+    }
+
+    #[test]
+    fn barycentric_of_vertices_is_unit_weight() {
+        let [a, b, c] = triangle();
+        assert_weights_near(Vec3::barycentric(a, a, b, c), (1.0, 0.0, 0.0));
+        assert_weights_near(Vec3::barycentric(b, a, b, c), (0.0, 1.0, 0.0));
+        assert_weights_near(Vec3::barycentric(c, a, b, c), (0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn barycentric_of_centroid_is_thirds() {
+        let [a, b, c] = triangle();
+        let third = 1.0 / 3.0;
+        let centroid = Vec3::centroid(&triangle());
+        assert_weights_near(Vec3::barycentric(centroid, a, b, c), (third, third, third));
+    }
+
+    #[test]
+    fn barycentric_reconstructs_point() {
+        let [a, b, c] = triangle();
+        let p = a * 0.2 + b * 0.5 + c * 0.3;
+        let (u, v, w) = Vec3::barycentric(p, a, b, c);
+        assert_weights_near((u, v, w), (0.2, 0.5, 0.3));
+        assert!((a * u + b * v + c * w).abs_diff_eq(&p, 1e-12));
+    }
+
+    #[test]
+    fn barycentric_outside_has_negative_weight() {
+        let [a, b, c] = triangle();
+        let (u, v, w) = Vec3::barycentric(b + (b - a), a, b, c);
+        assert!(u < 0.0);
+        assert!((u + v + w - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn barycentric_degenerate_is_zero() {
+        let a: Vec3<f64> = (0.0, 0.0, 0.0).into();
+        let b: Vec3<f64> = (1.0, 1.0, 1.0).into();
+        let c: Vec3<f64> = (2.0, 2.0, 2.0).into();
+        assert_eq!(Vec3::barycentric(b, a, b, c), (0.0, 0.0, 0.0));
+        assert_eq!(Vec3::barycentric(a, a, a, a), (0.0, 0.0, 0.0));
+    }
+
+    // ============ Color Tests ============
+
+    #[test]
+    fn srgb_round_trip_mid_gray() {
+        let gray: Vec3<f32> = Vec3::splat(0.5);
+        let linear = gray.to_linear();
+        // sRGB 0.5 is about 21.4% linear light, well below a naive 50%.
+        assert!((linear.x - 0.21404).abs() < 1e-5);
+        assert!(linear.to_srgb().abs_diff_eq(&gray, 1e-6));
+    }
+
+    #[test]
+    fn srgb_linear_segment_near_zero() {
+        let dark: Vec3<f32> = (0.0, 0.02, 0.04).into();
+        assert_eq!(
+            dark.to_linear(),
+            Vec3::from((0.0f32, 0.02 / 12.92, 0.04 / 12.92))
+        );
+
+        let linear_dark: Vec3<f32> = (0.0, 0.001, 0.003).into();
+        assert_eq!(
+            linear_dark.to_srgb(),
+            Vec3::from((0.0f32, 0.001 * 12.92, 0.003 * 12.92))
+        );
+    }
+
+    #[test]
+    fn srgb_segments_meet_at_threshold() {
+        // Both branches agree closely where the curve switches segments.
+        let below: Vec3<f32> = Vec3::splat(0.04045);
+        let above: Vec3<f32> = Vec3::splat(0.04046);
+        assert!((above.to_linear().x - below.to_linear().x).abs() < 1e-5);
+    }
+
+    #[test]
+    fn luminance_weights() {
+        assert_eq!(Vec3::<f32>::ZERO.luminance(), 0.0);
+        assert!((Vec3::<f32>::ONE.luminance() - 1.0).abs() < 1e-6);
+        assert!(Vec3::<f32>::Y.luminance() > Vec3::<f32>::X.luminance());
+        assert!(Vec3::<f32>::X.luminance() > Vec3::<f32>::Z.luminance());
+    }
+
+    // ============ Const Constructor Tests ============
+
+    #[test]
+    fn new_xyz_in_const_context() {
+        const V: Vec3<f32> = Vec3::new_xyz(1.0, 2.0, 3.0);
+        assert_eq!(V.x, 1.0);
+        assert_eq!(V.y, 2.0);
+        assert_eq!(V.z, 3.0);
+    }
+
+    #[test]
+    fn new_xyz_in_static_table() {
+        static NEIGHBORS: [Vec3<i32>; 2] = [Vec3::new_xyz(1, 0, 0), Vec3::new_xyz(-1, 0, 0)];
+        assert_eq!(NEIGHBORS[0] + NEIGHBORS[1], Vec3::<i32>::ZERO);
+    }
+
+    // ============ Generic Float Tests ============
+
+    // The float methods are written once against `num_traits::Float`, so a
+    // caller can stay generic over the precision too.
+    fn unit_length_error<T: Float>(v: Vec3<T>) -> T {
+        (v.normalize().length() - T::one()).abs()
+    }
+
+    #[test]
+    fn same_generic_length_for_f32_and_f64() {
+        let a: Vec3<f32> = (2.0, 3.0, 6.0).into();
+        let b: Vec3<f64> = (2.0, 3.0, 6.0).into();
+        assert_eq!(a.length(), 7.0f32);
+        assert_eq!(b.length(), 7.0f64);
+        assert!(unit_length_error(a) <= f32::EPSILON);
+        assert!(unit_length_error(b) <= f64::EPSILON);
+    }
 }
@@ -0,0 +1,172 @@
+use crate::vec3::Vec3;
+use num_traits::{Float, NumCast, ToPrimitive};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec4<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Float> Vec4<T> {
+    /// Divides xyz by w, mapping a homogeneous point back to 3D. A `w` of zero
+    /// (a direction, not a point) yields infinities or NaNs, so only call this
+    /// on points.
+    pub fn perspective_divide(&self) -> Vec3<T> {
+        Vec3 {
+            x: self.x / self.w,
+            y: self.y / self.w,
+            z: self.z / self.w,
+        }
+    }
+}
+
+impl<T: Copy> Vec4<T> {
+    /// Drops the w component without any perspective divide.
+    pub fn xyz(&self) -> Vec3<T> {
+        Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl_vec_common!(Vec4 { x, y, z, w });
+impl_vec_ops!(Vec4 { x, y, z, w });
+impl_vec_from_primitives!(Vec4 { x, y, z, w });
+
+impl<T: Float, U: ToPrimitive> From<(U, U, U, U)> for Vec4<T> {
+    fn from((x, y, z, w): (U, U, U, U)) -> Self {
+        Vec4 {
+            x: NumCast::from(x).unwrap(),
+            y: NumCast::from(y).unwrap(),
+            z: NumCast::from(z).unwrap(),
+            w: NumCast::from(w).unwrap(),
+        }
+    }
+}
+
+impl<T> From<(Vec3<T>, T)> for Vec4<T> {
+    fn from((xyz, w): (Vec3<T>, T)) -> Self {
+        Vec4 {
+            x: xyz.x,
+            y: xyz.y,
+            z: xyz.z,
+            w,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::op_ref)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_zero_vec() {
+        let vec4: Vec4<f32> = Vec4::new();
+        assert_eq!(vec4.x, 0.0);
+        assert_eq!(vec4.y, 0.0);
+        assert_eq!(vec4.z, 0.0);
+        assert_eq!(vec4.w, 0.0);
+    }
+
+    #[test]
+    fn create_broadcast_vec() {
+        let vec4: Vec4<f64> = Vec4::splat(11);
+        assert_eq!(vec4.x, 11.0);
+        assert_eq!(vec4.y, 11.0);
+        assert_eq!(vec4.z, 11.0);
+        assert_eq!(vec4.w, 11.0);
+    }
+
+    #[test]
+    fn create_full_vec() {
+        let vec4: Vec4<f32> = Vec4::from((1, 2, 3, 4));
+        assert_eq!(vec4.x, 1.0);
+        assert_eq!(vec4.y, 2.0);
+        assert_eq!(vec4.z, 3.0);
+        assert_eq!(vec4.w, 4.0);
+    }
+
+    #[test]
+    fn create_from_vec3_and_w() {
+        let xyz: Vec3<f32> = (1.0, 2.0, 3.0).into();
+        let vec4 = Vec4::from((xyz, 1.0));
+        assert_eq!(vec4.x, 1.0);
+        assert_eq!(vec4.y, 2.0);
+        assert_eq!(vec4.z, 3.0);
+        assert_eq!(vec4.w, 1.0);
+    }
+
+    #[test]
+    fn round_trip_through_vec3() {
+        let original: Vec3<f64> = (1.5, -2.0, 3.25).into();
+        let vec4: Vec4<f64> = (original, 1.0).into();
+        let back = vec4.xyz();
+        assert_eq!(back.x, 1.5);
+        assert_eq!(back.y, -2.0);
+        assert_eq!(back.z, 3.25);
+    }
+
+    #[test]
+    fn perspective_divide_by_w() {
+        let vec4: Vec4<f32> = (2.0, 4.0, 6.0, 2.0).into();
+        let result = vec4.perspective_divide();
+        assert_eq!(result.x, 1.0);
+        assert_eq!(result.y, 2.0);
+        assert_eq!(result.z, 3.0);
+    }
+
+    #[test]
+    fn xyz_ignores_w() {
+        let vec4: Vec4<f32> = (2.0, 4.0, 6.0, 2.0).into();
+        let result = vec4.xyz();
+        assert_eq!(result.x, 2.0);
+        assert_eq!(result.y, 4.0);
+        assert_eq!(result.z, 6.0);
+    }
+
+    #[test]
+    fn length_known_4d() {
+        let vec: Vec4<f32> = (1.0, 1.0, 1.0, 1.0).into();
+        assert_eq!(vec.length(), 2.0);
+    }
+
+    #[test]
+    fn dot_known_values() {
+        let a: Vec4<f32> = (1.0, 2.0, 3.0, 4.0).into();
+        let b: Vec4<f32> = (5.0, 6.0, 7.0, 8.0).into();
+        assert_eq!(a.dot(&b), 70.0);
+    }
+
+    #[test]
+    fn add_two_vectors() {
+        let a: Vec4<f32> = (1.0, 2.0, 3.0, 4.0).into();
+        let b: Vec4<f32> = (4.0, 3.0, 2.0, 1.0).into();
+        let result = &a + &b;
+        assert_eq!(result.x, 5.0);
+        assert_eq!(result.y, 5.0);
+        assert_eq!(result.z, 5.0);
+        assert_eq!(result.w, 5.0);
+    }
+
+    #[test]
+    fn mul_vector_by_scalar() {
+        let a: Vec4<f64> = (1.0, 2.0, 3.0, 4.0).into();
+        let result = a * 2.0;
+        assert_eq!(result.x, 2.0);
+        assert_eq!(result.y, 4.0);
+        assert_eq!(result.z, 6.0);
+        assert_eq!(result.w, 8.0);
+    }
+
+    #[test]
+    fn map_changes_element_type() {
+        let a: Vec4<f64> = (1.0, 2.0, 3.0, 4.0).into();
+        let result: Vec4<f32> = a.map(|c| c as f32);
+        assert_eq!(result, Vec4::from((1.0, 2.0, 3.0, 4.0)));
+    }
+}